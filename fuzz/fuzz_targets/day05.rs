@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc22::day05::Day05;
+use aoc22::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// any text either parses or is reported as an error, a panic is a crash
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = Day05::parse(input);
+    }
+});
@@ -0,0 +1,12 @@
+#![no_main]
+
+use aoc22::day07::Day07;
+use aoc22::solution::Solution;
+use libfuzzer_sys::fuzz_target;
+
+// any text either parses or is reported as an error, a panic is a crash
+fuzz_target!(|data: &[u8]| {
+    if let Ok(input) = std::str::from_utf8(data) {
+        let _ = Day07::parse(input);
+    }
+});
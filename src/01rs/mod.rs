@@ -0,0 +1,58 @@
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::input::{numbered_lines, parse_int};
+use crate::solution::Solution;
+
+
+// `size` elves, each carrying a few snacks
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for elf in 0..size {
+        if elf > 0 {
+            text.push('\n');
+        }
+        for _ in 0..rng.range(1, 8) {
+            text.push_str(&format!("{}\n", rng.range(1000, 70000)));
+        }
+    }
+    return text;
+}
+
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    type Input = Vec<i32>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<i32>, ParseError> {
+        let mut food_counts = Vec::new();
+
+        // blank lines separate the elves
+        let mut food_count = None;
+        for (line_number, ip) in numbered_lines(input) {
+            if ip.is_empty() {
+                food_counts.extend(food_count.take());
+            } else {
+                let calories = parse_int(ip).ok_or_else(|| ParseError::malformed(line_number, ip, 0..ip.len(), "a calorie count"))?;
+                let total = food_count.unwrap_or(0_i32).checked_add(calories)
+                    .ok_or_else(|| ParseError::malformed(line_number, ip, 0..ip.len(), "a calorie count the elf's total can hold"))?;
+                food_count = Some(total);
+            }
+        }
+        food_counts.extend(food_count);
+
+        food_counts.sort();
+        return Ok(food_counts);
+    }
+
+    // with fewer elves than asked about, all of them, so an input of none comes to 0
+    fn part1(food_counts: &Vec<i32>) -> i32 {
+        return food_counts.iter().rev().take(1).sum();
+    }
+
+    fn part2(food_counts: &Vec<i32>) -> i32 {
+        return food_counts.iter().rev().take(3).sum();
+    }
+}
@@ -0,0 +1,170 @@
+use itertools::Itertools;
+
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::input::numbered_lines;
+use crate::solution::Solution;
+
+struct ResultGuide {
+    loss_conditions: Vec<(String, String)>,
+    draw_conditions: Vec<(String, String)>,
+    win_conditions: Vec<(String, String)>,
+}
+
+struct ScoreGuide {
+    loss: i32,
+    draw: i32,
+    win: i32,
+
+    rock: i32,
+    paper: i32,
+    scissors: i32,
+}
+
+const SCORE_GUIDE: ScoreGuide = ScoreGuide {
+    loss: 0,
+    draw: 3,
+    win: 6,
+    rock: 1,
+    paper: 2,
+    scissors: 3,
+};
+
+fn get_choice_score(my_choice: &str) -> i32 {
+    return match my_choice {
+        "X" => SCORE_GUIDE.rock,
+        "Y" => SCORE_GUIDE.paper,
+        "Z" => SCORE_GUIDE.scissors,
+        _ => 0,
+    };
+}
+
+
+// second column is my choice
+pub fn get_total_score_by_choice(rounds: &[(String, String)]) -> i32 {
+    let result_guide: ResultGuide = ResultGuide {
+        loss_conditions: vec![
+            (String::from("A"), String::from("Z")),
+            (String::from("B"), String::from("X")),
+            (String::from("C"), String::from("Y")),
+        ],
+        draw_conditions: vec![
+            (String::from("A"), String::from("X")),
+            (String::from("B"), String::from("Y")),
+            (String::from("C"), String::from("Z")),
+        ],
+        win_conditions: vec![
+            (String::from("A"), String::from("Y")),
+            (String::from("B"), String::from("Z")),
+            (String::from("C"), String::from("X")),
+        ],
+    };
+
+    let mut rps_score: i32 = 0;
+
+    for choices in rounds {
+        let mut result_score = 0;
+        if result_guide.loss_conditions.contains(choices) {
+            result_score += SCORE_GUIDE.loss;
+        } else if result_guide.draw_conditions.contains(choices) {
+            result_score += SCORE_GUIDE.draw;
+        } else if result_guide.win_conditions.contains(choices) {
+            result_score += SCORE_GUIDE.win;
+        }
+
+        rps_score += result_score + get_choice_score(&choices.1);
+    }
+
+    return rps_score;
+}
+
+
+// second column is the result I need
+pub fn get_total_score_by_result(rounds: &[(String, String)]) -> i32 {
+    let mut rps_score: i32 = 0;
+
+    for (opp_choice, result) in rounds {
+        let mut result_score = 0;
+        let my_choice = match result.as_str() {
+            "X" => {
+                result_score += SCORE_GUIDE.loss;
+
+                match opp_choice.as_str() {
+                    "A" => "Z",
+                    "B" => "X",
+                    "C" => "Y",
+                    _ => "",
+                }
+            }
+            "Y" => {
+                result_score += SCORE_GUIDE.draw;
+
+                match opp_choice.as_str() {
+                    "A" => "X",
+                    "B" => "Y",
+                    "C" => "Z",
+                    _ => "",
+                }
+            }
+            "Z" => {
+                result_score += SCORE_GUIDE.win;
+
+                match opp_choice.as_str() {
+                    "A" => "Y",
+                    "B" => "Z",
+                    "C" => "X",
+                    _ => "",
+                }
+            }
+            _ => "",
+        };
+
+        rps_score += result_score + get_choice_score(my_choice);
+    }
+
+    return rps_score;
+}
+
+
+// `size` rounds
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        text.push_str(&format!("{} {}\n", rng.pick(&['A', 'B', 'C']), rng.pick(&['X', 'Y', 'Z'])));
+    }
+    return text;
+}
+
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    type Input = Vec<(String, String)>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<(String, String)>, ParseError> {
+        let mut rounds = Vec::new();
+        for (line_number, ip) in numbered_lines(input) {
+            if ip.is_empty() {
+                continue;
+            } else {
+                match ip.split(' ').collect_vec()[..] {
+                    [first @ ("A" | "B" | "C"), second @ ("X" | "Y" | "Z")] => {
+                        rounds.push((first.to_string(), second.to_string()));
+                    }
+                    _ => return Err(ParseError::malformed(line_number, ip, 0..ip.len(), "a round like \"A Y\"")),
+                }
+            }
+        }
+        return Ok(rounds);
+    }
+
+    fn part1(rounds: &Vec<(String, String)>) -> i32 {
+        return get_total_score_by_choice(rounds);
+    }
+
+    fn part2(rounds: &Vec<(String, String)>) -> i32 {
+        return get_total_score_by_result(rounds);
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use crate::error::ParseError;
+use crate::generate::{Rng, LOWERCASE, UPPERCASE};
+use crate::input::{lines, numbered_lines};
+use crate::solution::Solution;
+
+fn get_line_item_type_priority(ip: &str) -> i32 {
+    let (first, last) = ip.split_at(ip.len() / 2);
+
+    let first_set: HashSet<char> = HashSet::from_iter(first.chars());
+    let last_set: HashSet<char> = HashSet::from_iter(last.chars());
+
+    let item_type: &char = first_set.intersection(&last_set).next().unwrap();
+
+    // A is 65, a is 97
+    let i = *item_type as i32;
+    return if i < 97 { i - 65 + 27 } else { i - 97 + 1 };
+}
+
+fn get_group_badge_priority(ip1: &str, ip2: &str, ip3: &str) -> i32 {
+    let first_set: HashSet<char> = HashSet::from_iter(ip1.chars());
+    let second_set: HashSet<char> = HashSet::from_iter(ip2.chars());
+    let third_set: HashSet<char> = HashSet::from_iter(ip3.chars());
+
+    let first_two_item_types: HashSet<&char> = first_set.intersection(&second_set).collect();
+    let third_item_types: HashSet<&char> = third_set.iter().collect();
+    let item_type: &char = first_two_item_types.intersection(&third_item_types).next().unwrap();
+
+    // A is 65, a is 97
+    let i = *item_type as i32;
+    return if i < 97 { i - 65 + 27 } else { i - 97 + 1 };
+}
+
+
+// `size` groups of three rucksacks, each rucksack with one item in both compartments and each group sharing
+// one badge
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        let mut items = LOWERCASE.chars().chain(UPPERCASE.chars()).collect::<Vec<char>>();
+        rng.shuffle(&mut items);
+        let badge = items[0];
+        // the other items are split between the three elves, so the badge is all they have in common
+        for pool in items[1..].chunks(17) {
+            let (shared, rest) = (pool[0], &pool[1..]);
+            let (left_items, right_items) = rest.split_at(rest.len() / 2);
+            let length = rng.range(3, 16) as usize;
+            let mut left = (0..length - 1).map(|_| *rng.pick(left_items)).collect::<Vec<char>>();
+            let mut right = (0..length - 2).map(|_| *rng.pick(right_items)).collect::<Vec<char>>();
+            left.push(shared);
+            right.extend([shared, badge]);
+            rng.shuffle(&mut left);
+            rng.shuffle(&mut right);
+            text.extend(left.iter().chain(right.iter()));
+            text.push('\n');
+        }
+    }
+    return text;
+}
+
+
+// part 2 groups the lines three at a time, so every line has to be a rucksack and each group has to share a
+// badge; part 1 needs the compartments to be the same size and to share an item type
+fn lint_rucksacks(input: &str) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let lines = numbered_lines(input);
+    for &(line_number, ip) in &lines {
+        if ip.is_empty() {
+            problems.push(ParseError::malformed(line_number, ip, 0..ip.len(), "a rucksack, blank lines throw off the groups"));
+        } else if let Some((i, c)) = ip.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+            problems.push(ParseError::malformed(line_number, ip, i..i + c.len_utf8(), "an item type letter"));
+        } else if ip.len() % 2 != 0 {
+            problems.push(ParseError::malformed(
+                line_number, ip, 0..ip.len(), "an even number of items, half in each compartment"
+            ));
+        } else {
+            let (first, last) = ip.split_at(ip.len() / 2);
+            if !first.chars().any(|c| last.contains(c)) {
+                problems.push(ParseError::malformed(line_number, ip, 0..ip.len(), "compartments sharing an item type"));
+            }
+        }
+    }
+
+    for group in lines.chunks(3) {
+        if let [(_, ip1), (_, ip2), (line_number, ip3)] = group {
+            let letters = |ip: &str| ip.chars().all(|c| c.is_ascii_alphabetic());
+            let shares_badge = ip1.chars().any(|c| ip2.contains(c) && ip3.contains(c));
+            if letters(ip1) && letters(ip2) && letters(ip3) && !shares_badge {
+                let expected = "a badge item type shared with the two rucksacks before";
+                problems.push(ParseError::malformed(*line_number, ip3, 0..ip3.len(), expected));
+            }
+        }
+    }
+    if !lines.len().is_multiple_of(3) {
+        let expected = format!("{} more rucksacks to make the last group of three", 3 - lines.len() % 3);
+        problems.push(ParseError::missing(lines.len() + 1, &expected));
+    }
+    problems.sort_by_key(|problem| problem.line());
+    return problems;
+}
+
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    type Input = Vec<String>;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        for (line_number, ip) in numbered_lines(input) {
+            if let Some((i, c)) = ip.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                return Err(ParseError::malformed(line_number, ip, i..i + c.len_utf8(), "an item type letter"));
+            }
+        }
+        return Ok(lines(input));
+    }
+
+    fn part1(lines: &Vec<String>) -> i32 {
+        let mut priority_sum = 0;
+        for ip in lines {
+            if ip.is_empty() {
+                continue;
+            } else {
+                priority_sum += get_line_item_type_priority(ip);
+            }
+        }
+        return priority_sum;
+    }
+
+    fn part2(lines: &Vec<String>) -> i32 {
+        let mut priority_sum = 0;
+        let mut i = 0;
+        let mut ips: Vec<&String> = Vec::new();
+        for ip in lines {
+            i += 1;
+            ips.push(ip);
+            if ip.is_empty() {
+                continue;
+            } else if i % 3 == 0 {
+                if let [ip1, ip2, ip3] = ips[..] {
+                    priority_sum += get_group_badge_priority(ip1, ip2, ip3);
+                    ips.clear();
+                }
+            }
+        }
+        return priority_sum;
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        return lint_rucksacks(input);
+    }
+}
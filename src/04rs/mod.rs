@@ -0,0 +1,99 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::input::numbered_lines;
+use crate::solution::Solution;
+
+fn has_overlapping_pairs(pairs: &[(i32, i32)], fully_contained: bool) -> bool {
+    let mut result: bool = false;
+    for comb in pairs.iter().combinations(2) {
+        match comb[..] {
+            [p1, p2] => {
+                let (p1_start, p1_end) = p1;
+                let r1: HashSet<i32> = (*p1_start..=*p1_end).collect::<HashSet<i32>>();
+
+                let (p2_start, p2_end) = p2;
+                let r2: HashSet<i32> = (*p2_start..=*p2_end).collect::<HashSet<i32>>();
+
+                let intersect_count = r1.intersection(&r2).count();
+
+                if fully_contained {
+                    if [r1.len(), r2.len()].contains(&intersect_count) {
+                        result = true;
+                    }
+                } else if intersect_count > 0 {
+                    result = true;
+                }
+
+            }
+            _ => {
+                panic!("unexpected combination");
+            }
+        }
+    }
+    return result;
+}
+
+
+// the range starting `start` bytes into the line
+fn parse_range(line_number: usize, line: &str, start: usize, range: &str) -> Result<(i32, i32), ParseError> {
+    if let Some((start, end)) = range.split_once('-') {
+        if let (Ok(start), Ok(end)) = (start.parse::<i32>(), end.parse::<i32>()) {
+            return Ok((start, end));
+        }
+    }
+    return Err(ParseError::malformed(line_number, line, start..start + range.len(), "a section range like \"2-4\""));
+}
+
+
+// `size` pairs of section ranges
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        let mut ranges = Vec::new();
+        for _ in 0..2 {
+            let start = rng.range(1, 100);
+            ranges.push(format!("{}-{}", start, rng.range(start, 100)));
+        }
+        text.push_str(&format!("{}\n", ranges.join(",")));
+    }
+    return text;
+}
+
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    type Input = Vec<Vec<(i32, i32)>>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Vec<(i32, i32)>>, ParseError> {
+        let mut assignments = Vec::new();
+        for (line_number, line) in numbered_lines(input) {
+            if line.is_empty() {
+                continue;
+            } else {
+                let (r1, r2) = line
+                    .split_once(',')
+                    .ok_or_else(|| ParseError::malformed(line_number, line, 0..line.len(), "a pair of ranges like \"2-4,6-8\""))?;
+                assignments.push(vec![
+                    parse_range(line_number, line, 0, r1)?,
+                    parse_range(line_number, line, r1.len() + 1, r2)?,
+                ]);
+            }
+        }
+        return Ok(assignments);
+    }
+
+    fn part1(assignments: &Vec<Vec<(i32, i32)>>) -> usize {
+        return assignments.iter().filter(|pairs| has_overlapping_pairs(pairs, true)).count();
+    }
+
+    fn part2(assignments: &Vec<Vec<(i32, i32)>>) -> usize {
+        return assignments.iter().filter(|pairs| has_overlapping_pairs(pairs, false)).count();
+    }
+}
@@ -0,0 +1,291 @@
+use itertools::Itertools;
+
+use crate::error::ParseError;
+use crate::generate::{Rng, UPPERCASE};
+use crate::input::numbered_lines;
+use crate::solution::Solution;
+
+// (quantity to move, source stack, target stack)
+pub type Instruction = (i32, i32, i32);
+
+// the CrateMover 9000 moves crates one at a time, the 9001 keeps their order
+pub fn process_instructions(stacks: &mut [Vec<char>], instructions: &[Instruction], keep_order: bool) {
+    for &(quantity_to_move, source, target) in instructions {
+        let source_stack: &mut Vec<char> = &mut stacks[source as usize];
+        let mut source_crates_to_move = Vec::new();
+        for _ in 0..quantity_to_move {
+            source_crates_to_move.push(source_stack.pop().unwrap());
+        }
+
+        let target_stack: &mut Vec<char> = &mut stacks[target as usize];
+        if keep_order {
+            source_crates_to_move.reverse();
+        }
+        for crate_ in source_crates_to_move {
+            target_stack.push(crate_);
+        }
+    }
+}
+
+
+fn parse_stacks(input: &str) -> Result<(Vec<Vec<char>>, Vec<Instruction>), ParseError> {
+    let mut stacks: Vec<Vec<char>> = Vec::new();
+    let mut instructions: Vec<Instruction> = Vec::new();
+    let mut done_with_stacks = false;
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            done_with_stacks = true;
+            continue;
+        } else {
+            if done_with_stacks {
+                let expected = "an instruction like \"move 1 from 2 to 1\"";
+                let instruction = match line.split(' ').collect_vec()[..] {
+                    ["move", quantity_to_move, "from", source, "to", target] => {
+                        match (quantity_to_move.parse::<i32>(), source.parse::<usize>(), target.parse::<usize>()) {
+                            (Ok(quantity_to_move), Ok(source), Ok(target)) => (quantity_to_move, source, target),
+                            _ => return Err(ParseError::malformed(line_number, line, 0..line.len(), expected)),
+                        }
+                    }
+                    _ => return Err(ParseError::malformed(line_number, line, 0..line.len(), expected)),
+                };
+                let (quantity_to_move, source, target) = instruction;
+                for stack_number in [source, target] {
+                    if !(1..=stacks.len()).contains(&stack_number) {
+                        return Err(ParseError::malformed(
+                            line_number, line, 0..line.len(), &format!("stacks numbered 1 to {}", stacks.len())
+                        ));
+                    }
+                }
+                instructions.push((quantity_to_move, source as i32 - 1, target as i32 - 1));
+            } else {
+                if !line.contains('[') {
+                    // skip the stack numbers line
+                    continue;
+                }
+                if !line.is_ascii() {
+                    return Err(ParseError::malformed(line_number, line, 0..line.len(), "a row of crates like \"[A] [B]\""));
+                }
+                let number_of_stacks = (line.len()+1) / 4;
+                if stacks.is_empty() {
+                    for _ in 0..number_of_stacks {
+                        stacks.push(Vec::new());
+                    }
+                }
+                for (i, stack) in stacks.iter_mut().enumerate().take(number_of_stacks) {
+                    let crate_letter = (" ".to_string() + line).as_bytes()[i*4+2] as char;
+                    if crate_letter.is_ascii_uppercase() {
+                        // if there's a crate, prepend to stack
+                        stack.insert(0, crate_letter);
+                    } else if crate_letter != ' ' {
+                        return Err(ParseError::malformed(line_number, line, i*4+1..i*4+2, "a crate letter"));
+                    }
+                }
+            }
+        }
+    }
+    if !done_with_stacks {
+        return Err(ParseError::missing(input.lines().count() + 1, "a blank line between the stacks and the instructions"));
+    }
+    return Ok((stacks, instructions));
+}
+
+
+// the parser counts the stacks from the first row of crates and finds each crate by its column, so the rows
+// have to line up under single-digit stack numbers; the moves then have to find the crates they move and
+// leave every stack a top crate
+fn lint_stacks(input: &str) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let lines = numbered_lines(input);
+    let blank = lines.iter().position(|(_, line)| line.is_empty()).unwrap_or_else(|| {
+        problems.push(ParseError::missing(lines.len() + 1, "a blank line between the stacks and the instructions"));
+        lines.len()
+    });
+    let (drawing, instructions) = (&lines[..blank], lines.get(blank + 1..).unwrap_or(&[]));
+
+    let (rows, stack_count) = match drawing.last() {
+        Some(&(line_number, line)) if !line.contains('[') => {
+            // each stack number with where it starts on the line
+            let mut numbers = Vec::new();
+            let mut start = 0;
+            for part in line.split(' ') {
+                if !part.is_empty() {
+                    numbers.push((start, part));
+                }
+                start += part.len() + 1;
+            }
+            for (i, &(start, number)) in numbers.iter().enumerate() {
+                let span = start..start + number.len();
+                if number.len() > 1 {
+                    problems.push(ParseError::malformed(
+                        line_number, line, span, "a single-digit stack number, the stacks are 4 columns apart"
+                    ));
+                } else if number != (i + 1).to_string() {
+                    problems.push(ParseError::malformed(line_number, line, span, &format!("stack number {}", i + 1)));
+                } else if start != i * 4 + 1 {
+                    problems.push(ParseError::malformed(line_number, line, span, "a stack number under its crates"));
+                }
+            }
+            (&drawing[..drawing.len() - 1], numbers.len())
+        }
+        _ => {
+            let line_number = drawing.last().map_or(1, |(line_number, _)| line_number + 1);
+            problems.push(ParseError::missing(line_number, "a line of stack numbers under the crates"));
+            (drawing, drawing.first().map_or(0, |(_, line)| (line.len() + 1) / 4))
+        }
+    };
+
+    let mut heights = vec![0; stack_count];
+    for (row, &(line_number, line)) in rows.iter().enumerate() {
+        if !line.is_ascii() {
+            problems.push(ParseError::malformed(line_number, line, 0..line.len(), "a row of crates like \"[A] [B]\""));
+            continue;
+        }
+        let cells = (line.len() + 1) / 4;
+        if row == 0 && cells < stack_count {
+            // the rows below it can't add stacks
+            let expected = format!("a first row spanning all {} stacks", stack_count);
+            problems.push(ParseError::malformed(line_number, line, 0..line.len(), &expected));
+        }
+        for (i, span) in (0..cells).map(|i| i * 4..i * 4 + 3).enumerate() {
+            let cell = &line[span.clone()];
+            let letter = cell.as_bytes()[1];
+            if cell != "   " && !(cell.starts_with('[') && cell.ends_with(']') && letter.is_ascii_uppercase()) {
+                problems.push(ParseError::malformed(line_number, line, span, "a crate like \"[A]\" or three spaces"));
+            } else if cell != "   " && i >= stack_count {
+                let expected = format!("crates only over the {} numbered stacks", stack_count);
+                problems.push(ParseError::malformed(line_number, line, span, &expected));
+            } else if cell != "   " {
+                heights[i] += 1;
+            }
+        }
+        let rest_start = (cells * 4).min(line.len());
+        if !line[rest_start..].trim().is_empty() {
+            problems.push(ParseError::malformed(line_number, line, rest_start..line.len(), "a crate like \"[A]\" or three spaces"));
+        }
+    }
+
+    for &(line_number, line) in instructions {
+        if line.is_empty() {
+            continue;
+        }
+        let expected = "an instruction like \"move 1 from 2 to 1\"";
+        let (quantity_part, quantity, source, target) = match line.split(' ').collect_vec()[..] {
+            ["move", quantity_part, "from", source, "to", target] => {
+                match (quantity_part.parse::<usize>(), source.parse::<usize>(), target.parse::<usize>()) {
+                    (Ok(quantity), Ok(source), Ok(target)) => (quantity_part, quantity, source, target),
+                    _ => {
+                        problems.push(ParseError::malformed(line_number, line, 0..line.len(), expected));
+                        continue;
+                    }
+                }
+            }
+            _ => {
+                problems.push(ParseError::malformed(line_number, line, 0..line.len(), expected));
+                continue;
+            }
+        };
+        if [source, target].iter().any(|stack_number| !(1..=stack_count).contains(stack_number)) {
+            let expected = format!("stacks numbered 1 to {}", stack_count);
+            problems.push(ParseError::malformed(line_number, line, 0..line.len(), &expected));
+            continue;
+        }
+        if quantity > heights[source - 1] {
+            let expected = format!("at most the {} crates on stack {}", heights[source - 1], source);
+            let quantity_start = "move ".len();
+            problems.push(ParseError::malformed(line_number, line, quantity_start..quantity_start + quantity_part.len(), &expected));
+        }
+        let moved = quantity.min(heights[source - 1]);
+        heights[source - 1] -= moved;
+        heights[target - 1] += moved;
+    }
+    for (i, height) in heights.iter().enumerate() {
+        if *height == 0 {
+            problems.push(ParseError::missing(lines.len() + 1, &format!("a crate left on top of stack {}", i + 1)));
+        }
+    }
+    problems.sort_by_key(|problem| problem.line());
+    return problems;
+}
+
+
+pub fn get_top_crates(stacks: &[Vec<char>]) -> String {
+    let mut top_crates = "".to_string();
+
+    for stack in stacks {
+        top_crates.push(*stack.last().unwrap());
+    }
+
+    return top_crates;
+}
+
+
+fn get_top_crates_after(input: &(Vec<Vec<char>>, Vec<Instruction>), keep_order: bool) -> String {
+    let (stacks, instructions) = input;
+    let mut stacks = stacks.clone();
+    process_instructions(&mut stacks, instructions, keep_order);
+    return get_top_crates(&stacks);
+}
+
+
+// nine stacks and `size` moves, each move leaving at least one crate behind so every stack has a top crate
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let letters = UPPERCASE.chars().collect::<Vec<char>>();
+    let mut stacks = (0..9)
+        .map(|i| (0..rng.range(if i == 0 { 2 } else { 1 }, 8)).map(|_| *rng.pick(&letters)).collect::<Vec<char>>())
+        .collect::<Vec<Vec<char>>>();
+
+    let mut text = String::new();
+    let height = stacks.iter().map(|stack| stack.len()).max().unwrap();
+    for row in (0..height).rev() {
+        let cells = stacks.iter()
+            .map(|stack| match stack.get(row) {
+                Some(c) => format!("[{}]", c),
+                None => "   ".to_string(),
+            })
+            .collect::<Vec<String>>();
+        text.push_str(&format!("{}\n", cells.join(" ")));
+    }
+    let numbers = (1..=stacks.len()).map(|n| format!(" {} ", n)).collect::<Vec<String>>();
+    text.push_str(&format!("{}\n\n", numbers.join(" ")));
+
+    for _ in 0..size {
+        // there's always a stack to take from, there are more crates than stacks
+        let sources = (0..stacks.len()).filter(|i| stacks[*i].len() > 1).collect::<Vec<usize>>();
+        let source = *rng.pick(&sources);
+        let mut target = rng.below(stacks.len() - 1);
+        if target >= source {
+            target += 1;
+        }
+        let quantity = rng.range(1, stacks[source].len() as i64) as usize;
+        let split = stacks[source].len() - quantity;
+        let moved = stacks[source].split_off(split);
+        stacks[target].extend(moved);
+        text.push_str(&format!("move {} from {} to {}\n", quantity, source + 1, target + 1));
+    }
+    return text;
+}
+
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    type Input = (Vec<Vec<char>>, Vec<Instruction>);
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<(Vec<Vec<char>>, Vec<Instruction>), ParseError> {
+        return parse_stacks(input);
+    }
+
+    fn part1(input: &(Vec<Vec<char>>, Vec<Instruction>)) -> String {
+        return get_top_crates_after(input, false);
+    }
+
+    fn part2(input: &(Vec<Vec<char>>, Vec<Instruction>)) -> String {
+        return get_top_crates_after(input, true);
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        return lint_stacks(input);
+    }
+}
@@ -0,0 +1,60 @@
+use std::collections::HashSet;
+
+use crate::error::ParseError;
+use crate::generate::{Rng, LOWERCASE};
+use crate::solution::Solution;
+
+
+// number of characters read once the last `marker_size` of them are all different
+pub fn get_marker_end(signal: &str, marker_size: usize) -> usize {
+    let mut buffer: Vec<char> = Vec::new();
+
+    for (i, c) in signal.chars().enumerate() {
+        buffer.push(c);
+
+        if buffer.len() > marker_size {
+            buffer.remove(0);
+        }
+
+        if buffer.len() == marker_size
+                && buffer.iter().collect::<HashSet<&char>>().len() == buffer.len() {
+            return i+1;
+        }
+    }
+
+    return 0;
+}
+
+
+// `size` characters from only four letters, then the fourteen different ones that make both markers
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut signal = (0..size).map(|_| *rng.pick(&['a', 'b', 'c', 'd'])).collect::<Vec<char>>();
+    let mut letters = LOWERCASE.chars().collect::<Vec<char>>();
+    rng.shuffle(&mut letters);
+    signal.extend(&letters[..14]);
+    return format!("{}\n", signal.iter().collect::<String>());
+}
+
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    type Input = String;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        match input.lines().next() {
+            Some(signal) if !signal.is_empty() => return Ok(signal.to_string()),
+            _ => return Err(ParseError::missing(1, "a datastream signal")),
+        }
+    }
+
+    fn part1(signal: &String) -> usize {
+        return get_marker_end(signal, 4);
+    }
+
+    fn part2(signal: &String) -> usize {
+        return get_marker_end(signal, 14);
+    }
+}
@@ -0,0 +1,202 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::fmt;
+
+use num_bigint::{BigUint, ToBigUint};
+
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::input::numbered_lines;
+use crate::params::{Param, ParamSpec};
+use crate::solution::Solution;
+
+
+fn propagate_size(sizes: &mut HashMap<String, BigUint>, current_path_vec: &[String]) {
+    let current_path = current_path_vec.join("/");
+    for i in 1..current_path_vec.len() {
+        let parent_path = current_path_vec[0..(current_path_vec.len()-i)].join("/");
+        if let Some(v) = sizes.remove(&parent_path) {
+            sizes.insert(parent_path.clone(), v + &sizes[&current_path]);
+        } else {
+            unreachable!("every directory on the path was added when it was cd'd into");
+        }
+    }
+}
+
+
+fn parse_sizes(input: &str) -> Result<HashMap<String, BigUint>, ParseError> {
+    let mut sizes: HashMap<String, BigUint> = HashMap::new();
+
+    let mut current_path_vec: Vec<String> = Vec::new();
+    let mut current_path = "".to_string();
+    let mut file_traversal = false;
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        } else {
+            let line_parts: (&str,&str,) = line
+                .split_once(' ')
+                .ok_or_else(|| ParseError::malformed(line_number, line, 0..line.len(), "a command or a listing"))?;
+            match line_parts {
+                ("$", "ls") => {
+                    // begin listing
+                    file_traversal = true;
+                }
+                ("$", rest) => {
+                    // cd commands
+                    if file_traversal {
+                        // finished with dir
+                        file_traversal = false;
+                        propagate_size(&mut sizes, &current_path_vec);
+                    }
+                    match rest.split_once(' ').unwrap_or((rest, "")) {
+                        ("cd", "..") => {
+                            current_path_vec.pop();
+                            current_path = current_path_vec.join("/");
+                        }
+                        ("cd", dirname) => {
+                            current_path_vec.push(dirname.to_string());
+                            current_path = current_path_vec.join("/");
+                            sizes.insert(current_path.clone(), 0.to_biguint().unwrap());
+                        }
+                        _ => return Err(ParseError::malformed(line_number, line, "$ ".len()..line.len(), "\"ls\" or \"cd <dir>\"")),
+                    }
+                }
+                ("dir", _) => {
+                    // ignore
+                }
+                (file_size, _) => {
+                    let file_size = file_size
+                        .parse::<BigUint>()
+                        .map_err(|_| ParseError::malformed(line_number, line, 0..file_size.len(), "a file size or \"dir\""))?;
+                    if let Some(v) = sizes.remove(&current_path) {
+                        sizes.insert(current_path.clone(), v + file_size);
+                    } else {
+                        return Err(ParseError::malformed(line_number, line, 0..line.len(), "a \"$ cd\" before the first listing"));
+                    }
+                }
+            };
+        }
+    }
+    if file_traversal {
+        propagate_size(&mut sizes, &current_path_vec);
+    }
+    if !sizes.contains_key("/") {
+        return Err(ParseError::missing(1, "\"$ cd /\" to start from the root directory"));
+    }
+    return Ok(sizes);
+}
+
+
+// directories counted in part 1 are at most this big
+pub const SMALL_DIR_SIZE: Param<u64> = Param::new(7, "small_dir_size", 100000);
+
+pub const DISK_SIZE: Param<u64> = Param::new(7, "disk_size", 70000000);
+
+// unused space the update needs
+pub const SPACE_NEEDED: Param<u64> = Param::new(7, "space_needed", 30000000);
+
+pub const PARAMS: &[ParamSpec] = &[SMALL_DIR_SIZE.spec(), DISK_SIZE.spec(), SPACE_NEEDED.spec()];
+
+
+pub fn get_small_dirs_size(sizes: &HashMap<String, BigUint>, small_dir_size: u64) -> BigUint {
+    let max_size = small_dir_size.to_biguint().unwrap();
+    let size = sizes.values().filter(|x| **x <= max_size).sum();
+
+    return size;
+}
+
+
+pub fn get_size_to_free(sizes: &HashMap<String, BigUint>, disk_size: u64, space_needed: u64) -> Option<BigUint> {
+    let (disk_size, space_needed) = (disk_size.to_biguint().unwrap(), space_needed.to_biguint().unwrap());
+    let unused_space = if disk_size > sizes["/"] { disk_size - &sizes["/"] } else { 0.to_biguint().unwrap() };
+    // nothing to free when there's already space
+    if unused_space >= space_needed {
+        return Some(0.to_biguint().unwrap());
+    }
+    let space_to_free = space_needed - unused_space;
+    // None when even deleting / doesn't free enough
+    let size = sizes.values().filter(|x| **x >= space_to_free).min();
+
+    return size.cloned();
+}
+
+
+/// The size of the smallest directory that frees enough space, or that none does.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SizeToFree(pub Option<BigUint>);
+
+impl fmt::Display for SizeToFree {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match &self.0 {
+            Some(size) => write!(f, "{}", size),
+            None => write!(f, "no directory frees enough space"),
+        };
+    }
+}
+
+
+// `size` directories listed depth first, with enough in them that there's space to free
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(1);
+    let mut children = vec![Vec::new(); count];
+    for dir in 1..count {
+        children[rng.below(dir)].push(dir);
+    }
+    let mut files = (0..count)
+        .map(|_| (0..rng.range(0, 4)).map(|_| rng.range(1000, 300000)).collect::<Vec<i64>>())
+        .collect::<Vec<Vec<i64>>>();
+    let total = files.iter().flatten().sum::<i64>();
+    // files past this leave too little space for the update
+    let used_limit = (DISK_SIZE.default - SPACE_NEEDED.default) as i64;
+    files[0].push(cmp::max(used_limit - total, 0) + rng.range(1000, 300000));
+
+    let mut text = "$ cd /\n".to_string();
+    // directories still to list, None for going back up
+    let mut pending = vec![Some(0)];
+    while let Some(next) = pending.pop() {
+        let dir = match next {
+            Some(dir) => dir,
+            None => {
+                text.push_str("$ cd ..\n");
+                continue;
+            }
+        };
+        if dir != 0 {
+            text.push_str(&format!("$ cd d{}\n", dir));
+        }
+        text.push_str("$ ls\n");
+        for child in &children[dir] {
+            text.push_str(&format!("dir d{}\n", child));
+        }
+        for (i, file_size) in files[dir].iter().enumerate() {
+            text.push_str(&format!("{} f{}.{}\n", file_size, i, rng.pick(&["txt", "dat", "lst", "log"])));
+        }
+        for child in children[dir].iter().rev() {
+            pending.push(None);
+            pending.push(Some(*child));
+        }
+    }
+    return text;
+}
+
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    type Input = HashMap<String, BigUint>;
+    type Part1 = BigUint;
+    type Part2 = SizeToFree;
+
+    fn parse(input: &str) -> Result<HashMap<String, BigUint>, ParseError> {
+        return parse_sizes(input);
+    }
+
+    fn part1(sizes: &HashMap<String, BigUint>) -> BigUint {
+        return get_small_dirs_size(sizes, SMALL_DIR_SIZE.get());
+    }
+
+    fn part2(sizes: &HashMap<String, BigUint>) -> SizeToFree {
+        return SizeToFree(get_size_to_free(sizes, DISK_SIZE.get(), SPACE_NEEDED.get()));
+    }
+}
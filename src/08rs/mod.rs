@@ -0,0 +1,209 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::input::numbered_lines;
+use crate::solution::Solution;
+
+
+pub fn get_visible_trees_count(grid: &[Vec<i32>]) -> usize {
+    let mut visibles: HashSet<(usize,usize)> = HashSet::new();
+
+    // first row
+    for (col_number, size) in grid[0].iter().enumerate() {
+        visibles.insert((0, col_number));
+
+        let mut max_size = size;
+        for (row_number, tree_row) in grid.iter().enumerate().take(grid.len()-1).skip(1) {
+            if tree_row[col_number] <= *max_size {
+                // no visibility
+                continue;
+            } else {
+                // visible tree
+                visibles.insert((row_number, col_number));
+                max_size = &tree_row[col_number];
+            }
+        }
+    }
+
+    // middle rows
+    for (row_number, row) in grid.iter().enumerate() {
+        visibles.insert((row_number, 0));
+        visibles.insert((row_number, row.len()-1));
+
+        let mut max_size = row[0];
+        for (col_number, tree_size) in row.iter().enumerate().take(row.len()-1).skip(1) {
+            if *tree_size <= max_size {
+                // no visibility
+                continue;
+            } else {
+                // visible tree
+                visibles.insert((row_number, col_number));
+                max_size = *tree_size;
+            }
+        }
+
+        let mut max_size = row[row.len() -1];
+        for col_number in (1..row.len()-1).rev() {
+            if grid[row_number][col_number] <= max_size {
+                // no visibility
+                continue;
+            } else {
+                // visible tree
+                visibles.insert((row_number, col_number));
+                max_size = grid[row_number][col_number];
+            }
+        }
+    }
+
+    // last row
+    for (col_number, size) in grid[grid.len() -1].iter().enumerate() {
+        visibles.insert((grid.len() -1, col_number));
+
+        let mut max_size = size;
+        for row_number in (1..grid.len()-1).rev() {
+            if grid[row_number][col_number] <= *max_size {
+                // no visibility
+                continue;
+            } else {
+                // visible tree
+                visibles.insert((row_number, col_number));
+                max_size = &grid[row_number][col_number];
+            }
+        }
+    }
+
+    return visibles.len();
+}
+
+
+pub fn get_best_scenic(grid: &[Vec<i32>]) -> i32 {
+    let mut scores = Vec::new();
+
+    // edges are zero, their score is zero so exclude them
+    for row_number in 1..grid.len()-1 {
+        let row = &grid[row_number];
+        for col_number in 1..row.len()-1 {
+            // yeah it's about to get bad
+            let treehouse_size = grid[row_number][col_number];
+            let mut scenic_score = 1;
+
+            // look right (not to be confused with "looks right")
+            let mut tree_count = 0;
+            for tree_size in &row[col_number+1..] {
+                // these elves aren't worth it
+                if *tree_size >= treehouse_size {
+                    // last visible tree
+                    tree_count += 1;
+                    break;
+                } else {
+                    // visible tree
+                    tree_count += 1;
+                }
+            }
+            scenic_score *= tree_count;
+
+            // look left
+            let mut tree_count = 0;
+            for col_number_2 in (0..col_number).rev() {
+                if grid[row_number][col_number_2] >= treehouse_size {
+                    // last visible tree
+                    tree_count += 1;
+                    break;
+                } else {
+                    // visible tree
+                    tree_count += 1;
+                }
+            }
+            scenic_score *= tree_count;
+
+            // look up
+            let mut tree_count = 0;
+            for row_number_2 in (0..row_number).rev() {
+                // well this is going well (it's looking up haha)
+                if grid[row_number_2][col_number] >= treehouse_size {
+                    // last visible tree
+                    tree_count += 1;
+                    break;
+                } else {
+                    // visible tree
+                    tree_count += 1;
+                }
+            }
+            scenic_score *= tree_count;
+
+            // look down
+            let mut tree_count = 0;
+            for tree_row in &grid[row_number+1..] {
+                if tree_row[col_number] >= treehouse_size {
+                    // last visible tree
+                    tree_count += 1;
+                    break;
+                } else {
+                    // visible tree
+                    tree_count += 1;
+                }
+            }
+            scenic_score *= tree_count;
+
+            scores.push(scenic_score);
+        }
+    }
+
+    // every tree on the edge scores 0, so a grid of only those does too
+    return scores.iter().max().copied().unwrap_or(0);
+}
+
+
+// a `size` by `size` grid of tree heights
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size.max(1) {
+        let row = (0..size.max(1)).map(|_| rng.range(0, 10).to_string()).collect::<String>();
+        text.push_str(&format!("{}\n", row));
+    }
+    return text;
+}
+
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    type Input = Vec<Vec<i32>>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<Vec<i32>>, ParseError> {
+        let mut grid: Vec<Vec<i32>> = Vec::new();
+        for (line_number, line) in numbered_lines(input) {
+            if line.is_empty() {
+                continue;
+            }
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !c.is_ascii_digit()) {
+                return Err(ParseError::malformed(line_number, line, i..i + c.len_utf8(), "a tree height digit"));
+            }
+            let row = line
+                .chars()
+                .map(|c| c.to_digit(10).unwrap() as i32)
+                .collect_vec();
+            if !grid.is_empty() && row.len() != grid[0].len() {
+                return Err(ParseError::malformed(line_number, line, 0..line.len(), &format!("a row of {} trees", grid[0].len())));
+            }
+            grid.push(row);
+        }
+        if grid.is_empty() {
+            return Err(ParseError::missing(1, "a grid of trees"));
+        }
+        return Ok(grid);
+    }
+
+    fn part1(grid: &Vec<Vec<i32>>) -> usize {
+        return get_visible_trees_count(grid);
+    }
+
+    fn part2(grid: &Vec<Vec<i32>>) -> i32 {
+        return get_best_scenic(grid);
+    }
+}
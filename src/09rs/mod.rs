@@ -0,0 +1,162 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use serde_json::json;
+
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::input::numbered_lines;
+use crate::params::{Param, ParamSpec};
+use crate::solution::Solution;
+use crate::trace;
+
+
+fn get_new_pos(pos: (i32, i32), dir: &str) -> (i32,i32) {
+    let (x,y) = pos;
+    match dir {
+        "L" => (x-1,y),
+        "R" => (x+1,y),
+        "D" => (x,y-1),
+        "U" => (x,y+1),
+        _ => unreachable!("parse only lets L, R, D and U through")
+    }
+}
+
+
+fn get_follower_knot_pos(head_pos: (i32, i32), tail_pos: (i32, i32)) -> (i32, i32) {
+    let (head_x,head_y)  = head_pos;
+    let (tail_x, tail_y) = tail_pos;
+
+    // touching
+    if (head_x-tail_x).abs() <= 1 && (head_y-tail_y).abs() <= 1 {
+        // no move necessary
+        return tail_pos;
+    }
+
+    if head_x != tail_x && head_y != tail_y {
+        // diagonal
+        if tail_x < head_x {
+            if tail_y < head_y {
+                return (tail_x+1, tail_y+1);
+            } else {
+                return (tail_x+1, tail_y-1);
+            }
+        } else {
+            if tail_y < head_y {
+                return (tail_x-1, tail_y+1);
+            } else {
+                return (tail_x-1, tail_y-1);
+            }
+        }
+
+    } else {
+        // follow dir
+        if head_x == tail_x {
+            if head_y > tail_y {
+                // up
+                return get_new_pos(tail_pos, "U");
+            } else {
+                // down
+                return get_new_pos(tail_pos, "D");
+            }
+        } else {
+            if head_x > tail_x {
+                // right
+                return get_new_pos(tail_pos, "R");
+            } else {
+                // left
+                return get_new_pos(tail_pos, "L");
+            }
+        }
+    }
+}
+
+
+// (direction, number of steps)
+pub type Move = (String, i32);
+
+// knots in the rope for each part, the head's included, so there's always one
+pub const PART1_KNOTS: Param<i32> = Param::new(9, "part1_knots", 2).at_least(1);
+pub const PART2_KNOTS: Param<i32> = Param::new(9, "part2_knots", 10).at_least(1);
+
+pub const PARAMS: &[ParamSpec] = &[PART1_KNOTS.spec(), PART2_KNOTS.spec()];
+
+
+pub fn get_tail_visited_count(moves: &[Move], knot_count: i32) -> usize {
+    let mut visited: HashSet<(i32,i32)> = HashSet::new();
+    visited.insert((0,0));
+    assert!(knot_count >= 1, "a rope of {} knots has no head", knot_count);
+    let mut rope = Vec::new();
+    for _ in 0..knot_count {
+        rope.push((0,0));
+    }
+    let mut tracer = trace::start(9, json!({"knots": rope.len()}));
+    for (dir, move_number) in moves {
+        for _ in 0..*move_number {
+            let mut new_positions = Vec::new();
+            new_positions.push(get_new_pos(rope[0], dir));
+
+            let rope_rest = &rope[1..rope.len()];
+            for (i, pos) in rope_rest.iter().enumerate() {
+                let prev_pos = new_positions[i];
+                new_positions.push(get_follower_knot_pos(prev_pos, *pos));
+            }
+
+            for (i, new_pos) in new_positions.iter().enumerate() {
+                rope[i] = *new_pos;
+            }
+            visited.insert(rope[rope.len() -1]);
+        }
+        if let Some(tracer) = tracer.as_mut() {
+            tracer.step(json!({"move": format!("{} {}", dir, move_number), "knots": rope}));
+        }
+    }
+    return visited.len();
+}
+
+
+// `size` moves of the head
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        text.push_str(&format!("{} {}\n", rng.pick(&['U', 'D', 'L', 'R']), rng.range(1, 20)));
+    }
+    return text;
+}
+
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    type Input = Vec<Move>;
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Vec<Move>, ParseError> {
+        let mut moves = Vec::new();
+        for (line_number, line) in numbered_lines(input) {
+            if line.is_empty() {
+                continue;
+            } else {
+                match line.split(' ').collect_vec()[..] {
+                    [dir @ ("L" | "R" | "D" | "U"), n] => {
+                        let move_number = n
+                            .parse::<i32>()
+                            .map_err(|_| ParseError::malformed(line_number, line, dir.len() + 1..line.len(), "a number of steps"))?;
+                        moves.push((dir.to_string(), move_number));
+                    }
+                    _ => return Err(ParseError::malformed(line_number, line, 0..line.len(), "a move like \"R 4\"")),
+                }
+            }
+        }
+        return Ok(moves);
+    }
+
+    fn part1(moves: &Vec<Move>) -> usize {
+        return get_tail_visited_count(moves, PART1_KNOTS.get());
+    }
+
+    fn part2(moves: &Vec<Move>) -> usize {
+        return get_tail_visited_count(moves, PART2_KNOTS.get());
+    }
+}
@@ -0,0 +1,154 @@
+use std::collections::HashSet;
+
+use serde_json::json;
+
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::image::{Image, Palette};
+use crate::input::{lines, numbered_lines};
+use crate::params::{Param, ParamSpec};
+use crate::render::{Headless, Renderer};
+use crate::solution::Solution;
+use crate::trace;
+
+
+// the signal strength is sampled on the first cycle, then again every so many cycles
+pub const FIRST_SAMPLE: Param<i32> = Param::new(10, "first_sample", 20);
+pub const SAMPLE_EVERY: Param<i32> = Param::new(10, "sample_every", 40);
+pub const SAMPLES: Param<i32> = Param::new(10, "samples", 6);
+
+pub const PARAMS: &[ParamSpec] = &[FIRST_SAMPLE.spec(), SAMPLE_EVERY.spec(), SAMPLES.spec()];
+
+
+/// The cycles the signal strength is sampled on, 20, 60 ... 220 unless the parameters say otherwise.
+pub fn sample_cycles() -> HashSet<i32> {
+    let (first, every) = (FIRST_SAMPLE.get(), SAMPLE_EVERY.get());
+    return (0..SAMPLES.get()).map(|i| first + i * every).collect();
+}
+
+
+// runs the program, returning the signal strength total and the CRT screen; each cycle's frame is the screen
+// drawn so far
+pub fn run_program(program: &[String], cycle_sample_points: &HashSet<i32>, renderer: &mut dyn Renderer) -> (i32, String) {
+    let mut total = 0;
+    let mut screen = String::new();
+    let mut lines = program.iter();
+    let mut cycle = 0;
+    let mut x_register = 1;
+
+    let mut command_in_progress = Vec::new();
+    let mut command_cycles = 0;
+    let mut tracer = trace::start(10, json!({"instructions": program.len()}));
+    loop {
+        cycle += 1;
+
+        // start command if none in progress
+        if command_in_progress.is_empty() {
+            if let Some(line) = lines.next() {
+                if line.is_empty() {
+                    break;
+                } else {
+                    // transfer ownership from line
+                    command_in_progress = line.split(' ').map(String::from).collect::<Vec<String>>();
+                }
+            } else {
+                // end of file
+                break;
+            }
+        }
+
+        // perform CRT actions
+        if cycle_sample_points.contains(&cycle) {
+            total += cycle * x_register;
+        }
+
+        let draw_pos = (cycle - 1) % 40;
+        if draw_pos >= x_register-1 && draw_pos <= x_register +1 {
+            // drawing sprite
+            screen.push('#');
+        } else {
+            // empty
+            screen.push('.');
+        }
+        if cycle % 40 == 0 {
+            // EOL
+            screen.push('\n');
+        }
+        if renderer.is_active() {
+            renderer.frame(&screen);
+        }
+        // the register during the cycle, before an addx finishing in it takes effect
+        if let Some(tracer) = tracer.as_mut() {
+            tracer.step(json!({"cycle": cycle, "x": x_register, "instruction": command_in_progress.join(" ")}));
+        }
+
+        // process commands
+        match command_in_progress.iter().map(String::as_str).collect::<Vec<&str>>()[..] {
+            ["noop"] => {
+                // do nothing
+                command_in_progress.clear();
+            },
+            ["addx", value] => {
+                if command_cycles == 1 {
+                    // complete execution
+                    x_register += value.parse::<i32>().unwrap();
+                    command_in_progress.clear();
+                    command_cycles = 0;
+                } else {
+                    // command still running
+                    command_cycles += 1;
+                }
+            },
+            _ => unreachable!("parse only lets noop and addx <number> through"),
+        }
+    }
+    return (total, screen);
+}
+
+
+// `size` instructions, mostly additions
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut text = String::new();
+    for _ in 0..size {
+        if rng.chance(0.3) {
+            text.push_str("noop\n");
+        } else {
+            text.push_str(&format!("addx {}\n", rng.range(-10, 11)));
+        }
+    }
+    return text;
+}
+
+
+// the screen the program leaves lit, for the report
+pub fn draw(input: &str, palette: &Palette) -> Result<Image, ParseError> {
+    return Ok(Image::from_text(&Day10::part2(&Day10::parse(input)?), palette));
+}
+
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    type Input = Vec<String>;
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        for (line_number, line) in numbered_lines(input) {
+            match line.split(' ').collect::<Vec<&str>>()[..] {
+                ["noop"] | [""] => {}
+                ["addx", value] if value.parse::<i32>().is_ok() => {}
+                _ => return Err(ParseError::malformed(line_number, line, 0..line.len(), "\"noop\" or \"addx <number>\"")),
+            }
+        }
+        return Ok(lines(input));
+    }
+
+    fn part1(program: &Vec<String>) -> i32 {
+        return run_program(program, &sample_cycles(), &mut Headless).0;
+    }
+
+    fn part2(program: &Vec<String>) -> String {
+        return run_program(program, &sample_cycles(), &mut Headless).1;
+    }
+}
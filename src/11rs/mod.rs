@@ -0,0 +1,386 @@
+use std::collections::HashMap;
+use std::ops::Range;
+use std::rc::Rc;
+
+use itertools::Itertools;
+use num_bigint::{BigUint, ToBigUint, BigInt, ToBigInt};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::checkpoint::{self, Fingerprint};
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::{debug, info};
+use crate::input::numbered_blocks;
+use crate::params::{Param, ParamSpec};
+use crate::solution::Solution;
+use crate::trace;
+
+
+fn update_step(a: &mut BigInt, old_a: &mut BigInt, quotient: &BigInt) {
+    // adapted from: https://github.com/TheAlgorithms/Rust/blob/master/src/math/extended_euclidean_algorithm.rs
+    let temp = &a.clone();
+    *a = old_a.clone() - quotient * temp;
+    *old_a = temp.clone();
+}
+
+
+pub fn extended_euclidean_algorithm(a: &BigInt, b: &BigInt) -> (BigInt, BigInt, BigInt) {
+    // adapted from: https://github.com/TheAlgorithms/Rust/blob/master/src/math/extended_euclidean_algorithm.rs
+    let (mut old_r, mut rem) = (a.clone(), b.clone());
+    let (mut old_s, mut coeff_s) = (1_i32.to_bigint().unwrap(), 0_i32.to_bigint().unwrap());
+    let (mut old_t, mut coeff_t) = (0_i32.to_bigint().unwrap(), 1_i32.to_bigint().unwrap());
+
+    while rem != 0_i32.to_bigint().unwrap() {
+        let quotient = &old_r / &rem;
+
+        update_step(&mut rem, &mut old_r, &quotient);
+        update_step(&mut coeff_s, &mut old_s, &quotient);
+        update_step(&mut coeff_t, &mut old_t, &quotient);
+    }
+
+    (old_r, old_s, old_t)
+}
+
+
+fn mod_inv(x: &BigInt, n: &BigInt) -> Option<BigInt> {
+    // adapted from: https://github.com/TheAlgorithms/Rust/blob/master/src/math/chinese_remainder_theorem.rs
+    let (g, x, _) = extended_euclidean_algorithm(x, n);
+    if g == 1.to_bigint().unwrap() {
+        Some((x % n + n) % n)
+    } else {
+        None
+    }
+}
+
+
+pub fn chinese_remainder_theorem(residues: &[BigInt], modulli: &[BigInt]) -> Option<BigInt> {
+    // adapted from: https://github.com/TheAlgorithms/Rust/blob/master/src/math/chinese_remainder_theorem.rs
+    let prod = &modulli.iter().product::<BigInt>();
+
+    let mut sum = 0_i32.to_bigint().unwrap();
+
+    for (residue, modulus) in residues.iter().zip(modulli) {
+        let p = prod / modulus;
+        sum += residue * mod_inv(&p, modulus)? * p
+    }
+    Some(sum % prod)
+}
+
+
+type Operation<'a> = Rc<dyn Fn(&BigUint) -> BigUint + 'a>;
+type ThrowTest<'a> = Rc<dyn Fn(&BigUint) -> bool + 'a>;
+
+#[derive(Clone)]
+pub struct Monkey<'a> {
+    inspected_count: i32,
+    items: Vec<BigUint>,
+    operation: Operation<'a>,
+    // as written, to tell monkeys apart by
+    operation_text: String,
+    throw_test: (i32, ThrowTest<'a>),
+    test_success_target: i32,
+    test_fail_target: i32,
+}
+
+// where a line's text is, leaving out its indent and any trailing space
+fn trimmed_span(line: &str) -> Range<usize> {
+    let start = line.len() - line.trim_start().len();
+    return start..start + line.trim().len();
+}
+
+// the rest of a monkey's `index`th line after `prefix`, with its line number, the whole line and where in it
+// the rest starts
+fn get_field<'b>(
+    monkey_lines: &[(usize, &'b str)],
+    index: usize,
+    prefix: &str,
+    expected: &str,
+) -> Result<(usize, &'b str, usize, &'b str), ParseError> {
+    match monkey_lines.get(index) {
+        None => {
+            let last_line_number = monkey_lines.last().map(|(n, _)| *n).unwrap_or(0);
+            return Err(ParseError::missing(last_line_number + 1, expected));
+        }
+        Some(&(line_number, line)) => match line.trim().strip_prefix(prefix) {
+            Some(rest) => return Ok((line_number, line, trimmed_span(line).start + prefix.len(), rest)),
+            None => return Err(ParseError::malformed(line_number, line, trimmed_span(line), expected)),
+        },
+    }
+}
+
+impl<'a> Monkey<'a> {
+    fn from_lines(monkey_lines: &[(usize, &str)]) -> Result<Self, ParseError> {
+        get_field(monkey_lines, 0, "Monkey ", "a \"Monkey <n>:\" header")?;
+
+        let (line_number, line, mut item_start, items_part) =
+            get_field(monkey_lines, 1, "Starting items: ", "\"Starting items: <worry levels>\"")?;
+        let mut items = Vec::new();
+        for item in items_part.split(", ") {
+            items.push(
+                item.parse::<BigUint>()
+                    .map_err(|_| ParseError::malformed(line_number, line, item_start..item_start + item.len(), "a worry level"))?
+            );
+            item_start += item.len() + ", ".len();
+        }
+
+        let (line_number, line, op_start, op_part) =
+            get_field(monkey_lines, 2, "Operation: new = ", "\"Operation: new = old <+ or *> <old or number>\"")?;
+        // the operand is the last of the operation's words
+        let op_end = op_start + op_part.len();
+        let expected_operand = "\"old\" or a number";
+        let operation: Operation<'a> = match op_part.split(' ').collect_vec()[..] {
+            ["old", "*", "old"] => {
+                Rc::new(|x: &BigUint| -> BigUint { x * x })
+            },
+            ["old", "*", some_val] => {
+                let some_val_int = some_val
+                    .parse::<u32>()
+                    .map_err(|_| ParseError::malformed(line_number, line, op_end - some_val.len()..op_end, expected_operand))?;
+                Rc::new(move |x: &BigUint| -> BigUint { x * some_val_int.to_biguint().unwrap() })
+            },
+            ["old", "+", "old"] => {
+                Rc::new(|x: &BigUint| -> BigUint { x + x })
+            },
+            ["old", "+", some_val] => {
+                let some_val_int = some_val
+                    .parse::<u32>()
+                    .map_err(|_| ParseError::malformed(line_number, line, op_end - some_val.len()..op_end, expected_operand))?;
+                Rc::new(move |x: &BigUint| -> BigUint { x + some_val_int.to_biguint().unwrap() })
+            },
+            _ => return Err(ParseError::malformed(line_number, line, op_start..op_end, "\"old <+ or *> <old or number>\"")),
+        };
+
+        let (line_number, line, test_start, test_val) =
+            get_field(monkey_lines, 3, "Test: divisible by ", "\"Test: divisible by <number>\"")?;
+        let test_val_int = match test_val.parse::<i32>() {
+            Ok(test_val_int) if test_val_int > 0 => test_val_int,
+            _ => return Err(ParseError::malformed(line_number, line, test_start..test_start + test_val.len(), "a divisor above 0")),
+        };
+        let throw_test: ThrowTest<'a> =
+            Rc::new(move |x: &BigUint| -> bool { x % test_val_int.to_biguint().unwrap() == 0_u32.into() });
+
+        let mut targets = Vec::new();
+        for (index, prefix) in [(4, "If true: throw to monkey "), (5, "If false: throw to monkey ")] {
+            let expected = format!("\"{}<n>\"", prefix);
+            let (line_number, line, target_start, test_target) = get_field(monkey_lines, index, prefix, &expected)?;
+            targets.push(
+                test_target
+                    .parse::<i32>()
+                    .map_err(|_| ParseError::malformed(line_number, line, target_start..target_start + test_target.len(), "a monkey number"))?
+            );
+        }
+
+        return Ok(Self {
+            inspected_count: 0,
+            items,
+            operation,
+            operation_text: op_part.to_string(),
+            throw_test: (test_val_int, throw_test),
+            test_success_target: targets[0],
+            test_fail_target: targets[1],
+        });
+    }
+
+    // relief divides worry levels by 3 after each inspection; returns the monkey each item is thrown to with
+    // its new worry level, in the order they're thrown
+    fn business(&mut self, relief: bool) -> Vec<(usize, BigUint)> {
+        let mut throws = Vec::new();
+        for worry_level in &self.items {
+            let mut new_worry_level = (self.operation)(worry_level);
+            if relief {
+                new_worry_level /= 3.to_biguint().unwrap();
+            }
+            let (_, execute_test) = &self.throw_test;
+            let test_result = (execute_test)(&new_worry_level);
+            let target = if test_result { self.test_success_target } else { self.test_fail_target };
+            throws.push((target as usize, new_worry_level));
+
+            self.inspected_count += 1;
+        }
+        self.items.clear();
+
+        return throws;
+    }
+}
+
+
+// rounds for each part, part 1 with relief after inspections and part 2 without
+pub const PART1_ROUNDS: Param<i32> = Param::new(11, "part1_rounds", 20);
+pub const PART2_ROUNDS: Param<i32> = Param::new(11, "part2_rounds", 10000);
+
+pub const PARAMS: &[ParamSpec] = &[PART1_ROUNDS.spec(), PART2_ROUNDS.spec()];
+
+
+// what the rounds carry from one to the next, saved so a long run can be resumed
+#[derive(Serialize, Deserialize)]
+struct RoundsCheckpoint {
+    rounds_done: i32,
+    inspected_counts: Vec<i32>,
+    // the worry levels each monkey holds going into the next round, in decimal
+    items: Vec<Vec<String>>,
+}
+
+pub fn run_rounds(mut monkeys: Vec<Monkey>, rounds: i32, relief: bool) -> BigUint {
+    let mut new_targets: HashMap<usize, Vec<BigUint>> = HashMap::new();
+    let mut rounds_done = 0;
+
+    // the rounds don't depend on how many there'll be, so a checkpoint can be carried on past
+    let mut fingerprint = Fingerprint::new().number(relief as i64);
+    for monkey in &monkeys {
+        fingerprint = fingerprint.number(monkey.items.len() as i64);
+        for item in &monkey.items {
+            fingerprint = fingerprint.text(&item.to_string());
+        }
+        fingerprint = fingerprint
+            .text(&monkey.operation_text)
+            .number(monkey.throw_test.0 as i64)
+            .number(monkey.test_success_target as i64)
+            .number(monkey.test_fail_target as i64);
+    }
+    let mut checkpoint = checkpoint::open(if relief { "day11-relief" } else { "day11" }, fingerprint.finish());
+    let saved = checkpoint.as_ref().and_then(|c| c.resume::<RoundsCheckpoint>());
+    if let Some(saved) = saved.filter(|saved| saved.rounds_done <= rounds) {
+        info!("resuming after {} rounds", saved.rounds_done);
+        rounds_done = saved.rounds_done;
+        for ((monkey, count), items) in monkeys.iter_mut().zip(saved.inspected_counts).zip(saved.items) {
+            monkey.inspected_count = count;
+            monkey.items = items.iter().map(|item| item.parse::<BigUint>().unwrap()).collect();
+        }
+    }
+
+    let mut tracer = trace::start(11, json!({"monkeys": monkeys.len(), "rounds": rounds, "relief": relief}));
+    for i in rounds_done..rounds {
+        debug!("round {}", i + 1);
+        for (i_monkey, m) in monkeys.iter_mut().enumerate() {
+            if new_targets.contains_key(&i_monkey) {
+                m.items.append(new_targets.get_mut(&i_monkey).unwrap());
+            }
+            for (target, worry_level) in m.business(relief) {
+                if let Some(tracer) = tracer.as_mut() {
+                    tracer.step(json!({"round": i + 1, "monkey": i_monkey, "worry": worry_level.to_string(), "to": target}));
+                }
+                new_targets.entry(target).or_default().push(worry_level);
+            }
+        }
+        // with relief worry levels stay small, and dividing them doesn't survive the remainder trick
+        if !relief {
+            for target_items in new_targets.values_mut() {
+                let mut new_target_items: Vec<BigUint> = Vec::new();
+                for ti in target_items.iter() {
+                    let modulli = monkeys.iter().map(|x| x.throw_test.0.to_bigint().unwrap()).collect::<Vec<BigInt>>();
+                    let residues = monkeys.iter().map(|x| ti.to_bigint().unwrap() % x.throw_test.0.to_bigint().unwrap()).collect::<Vec<BigInt>>();
+                    match chinese_remainder_theorem(&residues, &modulli) {
+                        None => new_target_items.push(ti.clone()),
+                        Some(x) => new_target_items.push(x.to_biguint().unwrap()),
+                    };
+                }
+                target_items.clear();
+                target_items.append(&mut new_target_items);
+            }
+        }
+
+        // saved whenever it's due, and after the last round
+        if let Some(checkpoint) = checkpoint.as_mut().filter(|c| i + 1 == rounds || c.due()) {
+            checkpoint.save(&RoundsCheckpoint {
+                rounds_done: i + 1,
+                inspected_counts: monkeys.iter().map(|m| m.inspected_count).collect(),
+                items: monkeys
+                    .iter()
+                    .enumerate()
+                    .map(|(m, monkey)| {
+                        monkey.items.iter().chain(new_targets.get(&m).into_iter().flatten()).map(|x| x.to_string()).collect()
+                    })
+                    .collect(),
+            });
+        }
+    }
+
+    monkeys.sort_by(|a, b| a.inspected_count.partial_cmp(&b.inspected_count).unwrap());
+    let business_level = monkeys[monkeys.len()-2..]
+        .iter()
+        .fold(1.to_biguint().unwrap(), |acc,x| acc * x.inspected_count.to_biguint().unwrap());
+
+    return business_level;
+}
+
+
+// `size` monkeys (at least two), each testing a different prime as in the puzzle
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.max(2);
+    let mut primes = Vec::new();
+    let mut candidate = 2;
+    while primes.len() < count {
+        if primes.iter().all(|p| candidate % p != 0) {
+            primes.push(candidate);
+        }
+        candidate += 1;
+    }
+    rng.shuffle(&mut primes);
+
+    let mut monkeys = Vec::new();
+    for (i, prime) in primes.iter().enumerate() {
+        let items = (0..rng.range(1, 6)).map(|_| rng.range(50, 100).to_string()).collect::<Vec<String>>();
+        let operation = match rng.range(0, 10) {
+            0 => "old * old".to_string(),
+            1..=4 => format!("old * {}", rng.range(2, 20)),
+            _ => format!("old + {}", rng.range(1, 10)),
+        };
+        let mut targets = Vec::new();
+        while targets.len() < 2 {
+            let target = rng.below(count);
+            if target != i && (count == 2 || !targets.contains(&target)) {
+                targets.push(target);
+            }
+        }
+        monkeys.push(format!(
+            "Monkey {}:\n  Starting items: {}\n  Operation: new = {}\n  Test: divisible by {}\n    If true: throw to monkey {}\n    If false: throw to monkey {}\n",
+            i, items.join(", "), operation, prime, targets[0], targets[1]
+        ));
+    }
+    return monkeys.join("\n");
+}
+
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    type Input = Vec<Monkey<'static>>;
+    type Part1 = BigUint;
+    type Part2 = BigUint;
+
+    fn parse(input: &str) -> Result<Vec<Monkey<'static>>, ParseError> {
+        let monkey_blocks = numbered_blocks(input);
+        let mut monkeys = Vec::new();
+        for monkey_lines in &monkey_blocks {
+            monkeys.push(Monkey::from_lines(monkey_lines)?);
+        }
+        if monkeys.len() < 2 {
+            return Err(ParseError::missing(input.lines().count() + 1, "at least 2 monkeys"));
+        }
+
+        // throw targets have to be other monkeys
+        for (i, (monkey, monkey_lines)) in monkeys.iter().zip(&monkey_blocks).enumerate() {
+            for (target, (line_number, line)) in [monkey.test_success_target, monkey.test_fail_target].iter().zip(&monkey_lines[4..6]) {
+                if *target < 0 || *target as usize >= monkeys.len() || *target as usize == i {
+                    return Err(ParseError::malformed(
+                        *line_number, line, trimmed_span(line), &format!("a target other than itself, below {}", monkeys.len())
+                    ));
+                }
+            }
+        }
+        return Ok(monkeys);
+    }
+
+    fn part1(monkeys: &Vec<Monkey<'static>>) -> BigUint {
+        return run_rounds(monkeys.clone(), PART1_ROUNDS.get(), true);
+    }
+
+    fn part2(monkeys: &Vec<Monkey<'static>>) -> BigUint {
+        return run_rounds(monkeys.clone(), PART2_ROUNDS.get(), false);
+    }
+}
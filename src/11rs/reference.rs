@@ -0,0 +1,31 @@
+// Slow, direct versions of the day's answers, for differential tests against the shortcuts above.
+use num_bigint::BigUint;
+
+use super::Monkey;
+
+
+/// Plays the rounds exactly as described, keeping the whole worry levels however big they get.
+pub fn run_rounds(monkeys: &[Monkey], rounds: i32, relief: bool) -> BigUint {
+    let mut items = monkeys.iter().map(|m| m.items.clone()).collect::<Vec<Vec<BigUint>>>();
+    let mut inspected = vec![0u64; monkeys.len()];
+    for _ in 0..rounds {
+        for (i, monkey) in monkeys.iter().enumerate() {
+            for item in std::mem::take(&mut items[i]) {
+                inspected[i] += 1;
+                let mut worry = (monkey.operation)(&item);
+                if relief {
+                    worry /= 3u32;
+                }
+                let target = if &worry % BigUint::from(monkey.throw_test.0 as u32) == BigUint::from(0u32) {
+                    monkey.test_success_target
+                } else {
+                    monkey.test_fail_target
+                };
+                items[target as usize].push(worry);
+            }
+        }
+    }
+    inspected.sort();
+    inspected.reverse();
+    return BigUint::from(inspected[0]) * BigUint::from(inspected[1]);
+}
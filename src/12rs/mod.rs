@@ -0,0 +1,234 @@
+use std::collections::{HashSet, HashMap};
+use std::fmt;
+
+use itertools::Itertools;
+
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::image::{Image, Palette};
+use crate::input::{char_grid, numbered_lines};
+use crate::solution::Solution;
+
+
+// (x, y), x is col and y is row
+type Point = (i32, i32);
+
+
+// each square's elevation, a is 0 and z 25
+pub fn get_heights(grid: &[Vec<char>]) -> Vec<Vec<i32>> {
+    return grid.iter()
+        .map(|row| row.iter()
+            .map(|c| match c {
+                'S' => 0,
+                'E' => 25,
+                other => *other as i32 - 'a' as i32,
+            })
+            .collect())
+        .collect();
+}
+
+
+fn get_border_points(current_point: &(i32,i32), max_x: &i32, max_y: &i32) -> HashSet<(i32,i32)> {
+    let mut result = HashSet::new();
+    if current_point.0 > 0 {
+        result.insert((current_point.0-1, current_point.1));
+    }
+    if current_point.0 < *max_x {
+        result.insert((current_point.0+1, current_point.1));
+    }
+    if current_point.1 > 0 {
+        result.insert((current_point.0, current_point.1-1));
+    }
+    if current_point.1 < *max_y {
+        result.insert((current_point.0, current_point.1+1));
+    }
+    return result;
+}
+
+
+fn validate_points(grid: &[Vec<char>], current_point: &Point, border_points: &HashSet<Point>) -> (HashSet<Point>, HashSet<Point>) {
+    let mut valid_points = HashSet::new();
+    let mut invalid_points = HashSet::new();
+
+    let current_c: char = match grid[current_point.1 as usize][current_point.0 as usize] {
+        'S' => 'a',
+        'E' => 'z',
+        // 'E' => 'g',
+        other => other,
+    };
+    for point in border_points {
+        // y is row, x is col
+        let c: char = match grid[point.1 as usize][point.0 as usize] {
+            'S' => 'a',
+            'E' => 'z',
+            // 'E' => 'g',
+            other => other,
+        };
+        if ((c as i32) < (current_c as i32)) || (((c as i32) - (current_c as i32)) <= 1) {
+            valid_points.insert(*point);
+        } else {
+            invalid_points.insert(*point);
+        }
+    }
+
+    return (valid_points, invalid_points);
+}
+
+
+// distance to 'E' from the closest square marked any of `starts`, None when no path climbs up to it
+pub fn get_shortest_path(grid: &[Vec<char>], starts: &[char]) -> Option<i32> {
+    let (distances, ending_point) = explore(grid, starts);
+    return Some(distances[&ending_point]).filter(|&distance| distance < i32::MAX);
+}
+
+
+// each square's distance from the closest square marked `start`, -1 where it can't be reached
+pub fn get_distance_field(grid: &[Vec<char>], start: char) -> Vec<Vec<i32>> {
+    let (distances, _) = explore(grid, &[start]);
+    return (0..grid.len())
+        .map(|y| {
+            (0..grid[y].len())
+                .map(|x| match distances[&(x as i32, y as i32)] {
+                    i32::MAX => -1,
+                    distance => distance,
+                })
+                .collect()
+        })
+        .collect();
+}
+
+
+// the distances from the squares marked any of `starts` to every square, and where 'E' is
+fn explore(grid: &[Vec<char>], starts: &[char]) -> (HashMap<Point, i32>, Point) {
+    // dijstra's
+    let mut ending_point = (-1,-1);
+
+    let mut shortest_path_tree_set = HashSet::new();
+    let mut distances = HashMap::new();
+
+    // initialize distances
+    let max_x = (grid[0].len()-1) as i32;
+    let max_y = (grid.len()-1) as i32;
+    for (x,y) in (0..=max_x).cartesian_product(0..=max_y) {
+        if starts.contains(&grid[y as usize][x as usize]) {
+            distances.insert((x,y), 0);
+        } else {
+            if grid[y as usize][x as usize] == 'E' {
+                ending_point = (x,y);
+            }
+            distances.insert((x,y), i32::MAX);
+        }
+    }
+
+    // update distances
+    loop {
+        if shortest_path_tree_set.len() == (grid[0].len() * grid.len()) {
+            break;
+        }
+        // get next point, not already considered and smallest by distance
+        let current_point = *distances
+            .iter()
+            .filter(|(&k, &_v)| !shortest_path_tree_set.contains(&k))
+            .min_by_key(|(&_k,&v)| v)
+            .unwrap()
+            .0;
+
+        shortest_path_tree_set.insert(current_point);
+
+        let border_points = get_border_points(&current_point, &max_x, &max_y);
+        let (valid_points, _invalid_points) = validate_points(grid, &current_point, &border_points);
+        for adjacent_point in valid_points {
+            let mut new_dist = -1;
+            if distances[&current_point] < i32::MAX && distances[&current_point] + 1 < distances[&adjacent_point] {
+                new_dist = distances[&current_point] + 1;
+            }
+            if new_dist > -1 {
+                distances.insert(adjacent_point, new_dist);
+            }
+        }
+    }
+
+    return (distances, ending_point);
+}
+
+
+// a `size` by 2×`size` heightmap rising with a little noise from S on the left to E on the right
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let rows = size.max(2);
+    let columns = rows * 2;
+    let mut grid = Vec::new();
+    for _ in 0..rows {
+        let row = (0..columns)
+            .map(|x| {
+                let height = ((x * 26 / columns) as i64 + rng.range(-1, 2)).clamp(0, 25);
+                (b'a' + height as u8) as char
+            })
+            .collect::<Vec<char>>();
+        grid.push(row);
+    }
+    grid[rng.below(rows)][0] = 'S';
+    grid[rng.below(rows)][columns - 1] = 'E';
+    return grid.iter().map(|row| format!("{}\n", row.iter().collect::<String>())).collect();
+}
+
+
+// how far the search from S got across the heightmap, for the report
+pub fn draw(input: &str, palette: &Palette) -> Result<Image, ParseError> {
+    return Ok(Image::from_values(&get_distance_field(&Day12::parse(input)?, 'S'), palette));
+}
+
+
+/// The fewest steps up to E, or that none of the starting squares can reach it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Steps(pub Option<i32>);
+
+impl fmt::Display for Steps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self.0 {
+            Some(steps) => write!(f, "{}", steps),
+            None => write!(f, "E can't be reached"),
+        };
+    }
+}
+
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    type Input = Vec<Vec<char>>;
+    type Part1 = Steps;
+    type Part2 = Steps;
+
+    fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+        let mut row_length = None;
+        for (line_number, line) in numbered_lines(input) {
+            if line.is_empty() {
+                continue;
+            }
+            if let Some((i, c)) = line.char_indices().find(|(_, c)| !(c.is_ascii_lowercase() || *c == 'S' || *c == 'E')) {
+                return Err(ParseError::malformed(line_number, line, i..i + c.len_utf8(), "a height from a to z, S or E"));
+            }
+            match row_length {
+                Some(row_length) if row_length != line.len() => {
+                    return Err(ParseError::malformed(line_number, line, 0..line.len(), &format!("a row of {} squares", row_length)));
+                }
+                _ => row_length = Some(line.len()),
+            }
+        }
+        for marker in ['S', 'E'] {
+            if input.matches(marker).count() != 1 {
+                return Err(ParseError::missing(1, &format!("exactly one {} square", marker)));
+            }
+        }
+        return Ok(char_grid(input));
+    }
+
+    fn part1(grid: &Vec<Vec<char>>) -> Steps {
+        return Steps(get_shortest_path(grid, &['S']));
+    }
+
+    fn part2(grid: &Vec<Vec<char>>) -> Steps {
+        // S is at elevation a too
+        return Steps(get_shortest_path(grid, &['S', 'a']));
+    }
+}
@@ -0,0 +1,332 @@
+use std::collections::HashMap;
+
+use itertools::Itertools;
+
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::input::numbered_lines;
+use crate::solution::Solution;
+
+
+fn split_keep(text: &str) -> Vec<String> {
+    let mut result = Vec::new();
+    let mut last = 0;
+    for (index, matched) in text.match_indices(|c: char| !(c.is_numeric())) {
+        if last != index {
+            result.push(text[last..index].to_string());
+        }
+        result.push(matched.to_string());
+        last = index + matched.len();
+    }
+    if last < text.len() {
+        result.push(text[last..].to_string());
+    }
+    return result;
+}
+
+
+fn compare_packets(left_packet: &str, right_packet: &str) -> bool {
+    let left_split = split_keep(left_packet);
+    let right_split = split_keep(right_packet);
+    let mut left_packet_vec = left_split.iter().filter(|x| x[..] != *",").map(|x| x.to_owned()).collect_vec();
+    let mut right_packet_vec = right_split.iter().filter(|x| x[..] != *",").map(|x| x.to_owned()).collect_vec();
+
+    // transform data, add lists when matching an integer to another list
+    let mut i = 0;
+    loop {
+        if i >= left_packet_vec.len() || i >= right_packet_vec.len() {
+            break;
+        }
+        if left_packet_vec[i][..] == *"[" && right_packet_vec[i][..] != *"[" {
+            if right_packet_vec[i][..] != *"]" {
+                right_packet_vec.insert(i, "[".to_string());
+                right_packet_vec.insert(i+2, "]".to_string());
+            } else {
+                break;
+            }
+        } else if right_packet_vec[i][..] == *"[" && left_packet_vec[i][..] != *"[" {
+            if left_packet_vec[i][..] != *"]" {
+                left_packet_vec.insert(i, "[".to_string());
+                left_packet_vec.insert(i+2, "]".to_string());
+            } else {
+                break;
+            }
+        } else if left_packet_vec[i] != right_packet_vec[i] {
+            break;
+        }
+        i += 1;
+    }
+
+    // compare packets
+    for (i, c) in left_packet_vec.iter().enumerate() {
+        if c[..] == *"]" && right_packet_vec[i][..] != *"]" {
+            // left side ran out of items
+            return true;
+        } else if right_packet_vec[i][..] == *"]" && c[..] != *"]" {
+            // right side ran out of items
+            return false;
+        } else if c[..] == *"[" || c[..] == *"]" || right_packet_vec[i][..] == *"[" || right_packet_vec[i][..] == *"]" {
+            continue;
+        } else {
+            // must be numbers
+            let left_int = c.parse::<i32>().unwrap();
+            let right_int = right_packet_vec[i].parse::<i32>().unwrap();
+
+            if left_int < right_int {
+                return true;
+            } else if right_int < left_int {
+                return false;
+            } else {
+                continue;
+            }
+        }
+    }
+
+    unreachable!("parse rejects packets that compare equal");
+}
+
+
+// the packet as the comparison sees it, every list of a single number being that number: two packets compare
+// equal exactly when these are the same
+fn canonical(packet: &str) -> String {
+    // the items of each list still open, outermost first
+    let mut lists: Vec<Vec<String>> = vec![Vec::new()];
+    for token in split_keep(packet) {
+        match token.as_str() {
+            "[" => lists.push(Vec::new()),
+            "]" => {
+                let items = lists.pop().unwrap();
+                let list = match &items[..] {
+                    [item] if !item.starts_with('[') => item.clone(),
+                    _ => format!("[{}]", items.join(",")),
+                };
+                lists.last_mut().unwrap().push(list);
+            }
+            "," => {}
+            number => lists.last_mut().unwrap().push(number.parse::<i32>().unwrap().to_string()),
+        }
+    }
+    return lists.concat().concat();
+}
+
+const DIVIDERS: [&str; 2] = ["[[2]]", "[[6]]"];
+
+// the packets so far by how the comparison sees them, each with its line, line 0 for the dividers
+fn dividers_seen() -> HashMap<String, usize> {
+    return DIVIDERS.iter().map(|divider| (canonical(divider), 0)).collect();
+}
+
+// part 2 sorts every packet with the dividers, so none can compare equal to another; `left_number` is the line
+// of the left packet of the pair, if `line` is the right one
+fn check_distinct(
+    seen: &mut HashMap<String, usize>, line_number: usize, line: &str, left_number: Option<usize>
+) -> Result<(), ParseError> {
+    let expected = match seen.get(&canonical(line)) {
+        None => {
+            seen.insert(canonical(line), line_number);
+            return Ok(());
+        }
+        Some(0) => format!("a packet different from the divider packets {} and {}", DIVIDERS[0], DIVIDERS[1]),
+        Some(earlier) if Some(*earlier) == left_number => "a packet different from the left one".to_string(),
+        Some(earlier) => format!("a packet different from the one on line {}", earlier),
+    };
+    return Err(ParseError::malformed(line_number, line, 0..line.len(), &expected));
+}
+
+
+// a packet is a bracketed list of numbers and lists
+fn check_packet(line_number: usize, line: &str) -> Result<(), ParseError> {
+    if !line.starts_with('[') {
+        return Err(ParseError::malformed(line_number, line, 0..line.len(), "a packet starting with ["));
+    }
+    let mut depth = 0;
+    for (i, c) in line.char_indices() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth -= 1,
+            ',' | '0'..='9' => {}
+            _ => return Err(ParseError::malformed(line_number, line, i..i + c.len_utf8(), "a digit, a comma or a bracket")),
+        }
+        if depth == 0 && i + 1 != line.len() {
+            return Err(ParseError::malformed(line_number, line, i + 1..line.len(), "the end of the packet"));
+        }
+    }
+    if depth != 0 {
+        return Err(ParseError::malformed(line_number, line, 0..line.len(), "balanced brackets"));
+    }
+    let mut start = 0;
+    for number in line.split(['[', ']', ',']) {
+        if !number.is_empty() && number.parse::<i32>().is_err() {
+            return Err(ParseError::malformed(line_number, line, start..start + number.len(), "a number up to 2147483647"));
+        }
+        start += number.len() + 1;
+    }
+    return Ok(());
+}
+
+
+// the parser pairs lines by their index, so a missing or doubled line shifts every pair after it; the
+// comparison has no answer for equal packets
+fn lint_pairs(input: &str) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let mut seen = dividers_seen();
+    let lines = numbered_lines(input);
+    let mut pair: Vec<(usize, &str)> = Vec::new();
+    for &(line_number, line) in &lines {
+        if line.is_empty() {
+            match pair.len() {
+                0 => {
+                    let expected = "a packet, pairs are one blank line apart";
+                    problems.push(ParseError::malformed(line_number, line, 0..line.len(), expected));
+                }
+                1 => problems.push(ParseError::missing(line_number, "the right packet of the pair")),
+                _ => {}
+            }
+            pair.clear();
+            continue;
+        }
+        if pair.len() >= 2 {
+            problems.push(ParseError::malformed(line_number, line, 0..line.len(), "a blank line between pairs"));
+        }
+        let left_number = pair.first().map(|(left_number, _)| *left_number);
+        if let Err(problem) = check_packet(line_number, line).and_then(|_| check_distinct(&mut seen, line_number, line, left_number)) {
+            problems.push(problem);
+        }
+        pair.push((line_number, line));
+    }
+    if pair.len() == 1 {
+        problems.push(ParseError::missing(lines.len() + 1, "the right packet of the last pair"));
+    }
+    return problems;
+}
+
+
+pub fn get_in_order_index_sum(pairs: &[(String, String)]) -> usize {
+    let mut total = 0;
+    for (i, (left_packet, right_packet)) in pairs.iter().enumerate() {
+        if compare_packets(left_packet, right_packet) {
+            total += i + 1;
+        }
+    }
+    return total;
+}
+
+
+pub fn get_decoder_key(pairs: &[(String, String)]) -> i32 {
+    let mut packets = Vec::new();
+    for (left_packet, right_packet) in pairs {
+        packets.push(left_packet.clone());
+        packets.push(right_packet.clone());
+    }
+
+    // dividers
+    packets.extend(DIVIDERS.map(String::from));
+
+    // sort in order
+    let mut sorted_packets = Vec::new();
+    for p in packets {
+        // idk why I have to implement this myself
+        if sorted_packets.is_empty() {
+            sorted_packets.push(p.clone());
+        } else {
+            let mut inserted = false;
+            for (i, sp) in sorted_packets.iter().enumerate() {
+                if compare_packets(&p, sp) {
+                    sorted_packets.insert(i, p.clone());
+                    inserted = true;
+                    break;
+                }
+            }
+            if !inserted {
+                sorted_packets.push(p.clone());
+            }
+        }
+    }
+
+    let mut sorted_packets_iter = sorted_packets.iter();
+    let mut total = sorted_packets_iter.position(|x| x == &"[[2]]".to_string()).unwrap() + 1;
+    total *= total + (sorted_packets_iter.position(|x| x == &"[[6]]".to_string()).unwrap() + 1);
+
+    return total as i32;
+}
+
+
+fn generate_list(rng: &mut Rng, depth: usize, min_length: i64) -> Vec<String> {
+    return (0..rng.range(min_length, 5))
+        .map(|_| {
+            if depth < 3 && rng.chance(0.3) {
+                format!("[{}]", generate_list(rng, depth + 1, 0).join(","))
+            } else {
+                rng.range(0, 11).to_string()
+            }
+        })
+        .collect();
+}
+
+// `size` pairs of packets; each packet ends in a number that differs within the pair and then the pair's
+// index, so no two packets compare equal, not even to the divider packets
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let mut pairs = Vec::new();
+    for i in 0..size {
+        let left_last = rng.range(0, 10);
+        let right_last = (left_last + rng.range(1, 10)) % 10;
+        let mut pair = String::new();
+        for last in [left_last, right_last] {
+            let mut items = generate_list(rng, 1, 1);
+            items.extend([last.to_string(), i.to_string()]);
+            pair.push_str(&format!("[{}]\n", items.join(",")));
+        }
+        pairs.push(pair);
+    }
+    return pairs.join("\n");
+}
+
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    type Input = Vec<(String, String)>;
+    type Part1 = usize;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Vec<(String, String)>, ParseError> {
+        let mut pairs = Vec::new();
+        let mut left_packet = String::new();
+        let mut right_packet = String::new();
+        let mut seen = dividers_seen();
+        for (i, (line_number, line)) in numbered_lines(input).into_iter().enumerate() {
+            if i % 3 == 2 {
+                if !line.is_empty() {
+                    return Err(ParseError::malformed(line_number, line, 0..line.len(), "a blank line between pairs"));
+                }
+                pairs.push((left_packet.clone(), right_packet.clone()));
+            } else {
+                check_packet(line_number, line)?;
+                check_distinct(&mut seen, line_number, line, if i % 3 == 1 { Some(line_number - 1) } else { None })?;
+                if i % 3 == 0 {
+                    left_packet = line.to_string();
+                } else {
+                    right_packet = line.to_string();
+                }
+            }
+        }
+        match input.lines().count() % 3 {
+            2 => pairs.push((left_packet, right_packet)),
+            0 => {}
+            _ => return Err(ParseError::missing(input.lines().count() + 1, "the right packet of the last pair")),
+        }
+        return Ok(pairs);
+    }
+
+    fn part1(pairs: &Vec<(String, String)>) -> usize {
+        return get_in_order_index_sum(pairs);
+    }
+
+    fn part2(pairs: &Vec<(String, String)>) -> i32 {
+        return get_decoder_key(pairs);
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        return lint_pairs(input);
+    }
+}
@@ -0,0 +1,317 @@
+use std::collections::HashSet;
+use std::ops::Range;
+
+use itertools::Itertools;
+use serde_json::json;
+
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::image::{Image, Palette};
+use crate::input::{numbered_lines, parse_tuple_int};
+use crate::render::{Headless, Renderer};
+use crate::solution::Solution;
+use crate::trace;
+
+
+// the cave grid and where sand pours in from
+pub type Cave = (Vec<Vec<char>>, (i32,i32));
+
+/// The largest x or y a rock path can reach, the grid is allocated to fit every path.
+pub const MAX_COORDINATE: i32 = 2000;
+
+
+fn draw_grid(grid: &[Vec<char>]) -> String {
+    let mut frame = String::new();
+    for row in grid {
+        frame.extend(row);
+        frame.push('\n');
+    }
+    return frame;
+}
+
+
+fn tuple_int_range_expand(t1: (i32, i32), t2: (i32, i32)) -> Vec<(i32, i32)> {
+    let mut range = Vec::new();
+    if t1.0 != t2.0 && t1.1 != t2.1 {
+        unreachable!("parse rejects diagonal segments");
+    }
+    if t1.0 != t2.0 {
+        // x range
+        if t1.0 < t2.0 {
+            for x in t1.0..=t2.0 {
+                range.push((x,t1.1));
+            }
+        } else {
+            for x in t2.0..=t1.0 {
+                range.push((x,t1.1));
+            }
+        }
+    } else {
+        // y range
+        if t1.1 < t2.1 {
+            for y in t1.1..=t2.1 {
+                range.push((t1.0,y));
+            }
+        } else {
+            for y in t2.1..=t1.1 {
+                range.push((t1.0,y));
+            }
+        }
+    }
+
+    return range;
+}
+
+// each point of a rock path with where it is on the line, to point at it
+type Path = Vec<(Range<usize>, (i32, i32))>;
+
+fn parse_path(line_number: usize, line: &str) -> Result<Path, ParseError> {
+    let mut points = Vec::new();
+    let mut start = 0;
+    for segment in line.split(" -> ") {
+        let span = start..start + segment.len();
+        start += segment.len() + " -> ".len();
+        match parse_tuple_int(segment) {
+            Some(point) if point.0 >= 0 && point.1 >= 0 => {
+                if point.0 > MAX_COORDINATE || point.1 > MAX_COORDINATE {
+                    return Err(ParseError::malformed(
+                        line_number, line, span, &format!("a point no further out than {}", MAX_COORDINATE)
+                    ));
+                }
+                points.push((span, point));
+            }
+            _ => return Err(ParseError::malformed(line_number, line, span, "a point like \"498,4\"")),
+        }
+    }
+    return Ok(points);
+}
+
+// the points that end a diagonal segment, rocks are only drawn in horizontal and vertical lines
+fn diagonal_ends(points: &Path) -> Vec<Range<usize>> {
+    return points
+        .iter()
+        .zip(points.iter().skip(1))
+        .filter(|((_, start), (_, end))| start.0 != end.0 && start.1 != end.1)
+        .map(|(_, (end_span, _))| end_span.clone())
+        .collect_vec();
+}
+
+fn parse_grid(input: &str) -> Result<Cave, ParseError> {
+    // produce the rocks
+    let mut rocks: HashSet<(i32,i32)> = HashSet::new();
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        }
+        let segments = parse_path(line_number, line)?;
+        if let Some(end_span) = diagonal_ends(&segments).into_iter().next() {
+            return Err(ParseError::malformed(line_number, line, end_span, "a point in line with the previous one"));
+        }
+        for (&(_, start), &(_, end)) in segments.iter().zip(segments.iter().skip(1)) {
+            rocks.extend(tuple_int_range_expand(start, end));
+        }
+    }
+    if rocks.is_empty() {
+        return Err(ParseError::missing(1, "a rock path"));
+    }
+
+    // determine x bounds (min, max), and y max
+    let mut x_bounds = (i32::MAX,i32::MIN);
+    let mut y_max = 0;
+    for rock in rocks.iter() {
+        if rock.0 < x_bounds.0 {
+            x_bounds.0 = rock.0;
+        }
+        if rock.0 > x_bounds.1 {
+            x_bounds.1 = rock.0;
+        }
+        if rock.1 > y_max {
+            y_max = rock.1;
+        }
+    }
+
+    // produce grid
+    let mut x_mod = x_bounds.0 - 1;
+    let x_start = 500 - x_mod - y_max - 2;
+    let mut x_end = 500 - x_mod + y_max + 2;
+    let x_shift = -x_start;
+    x_end += x_shift;
+    x_mod -= x_shift;
+    let sand_source = (500-x_mod, 0);
+    let mut grid = Vec::new();
+    for y in 0..=y_max {
+        grid.push(vec!['.']);
+        for x in 0..x_end {
+            let rock_check_x = x_bounds.0 + x - x_shift;
+            if (x,y) == (sand_source.0-1,sand_source.1) {
+                grid[y as usize].push('+');
+            } else if rocks.contains(&(rock_check_x, y)) {
+                // it's a rock
+                grid[y as usize].push('#');
+            } else {
+                grid[y as usize].push('.');
+            }
+        }
+        grid[y as usize].push('.');
+    }
+    grid.push((0..(x_end+2)).map(|_| '.').collect_vec());
+    grid.push((0..(x_end+2)).map(|_| '#').collect_vec());
+    return Ok((grid, sand_source));
+}
+
+
+// without a floor, sand reaching the row above it falls into the abyss and the count stops
+pub fn process_sand(grid: &mut [Vec<char>], sand_source: (i32,i32), floor: bool, renderer: &mut dyn Renderer) -> i32 {
+    let mut total = 0;
+    if renderer.is_active() {
+        renderer.frame(&draw_grid(grid));
+    }
+    let mut stop = false;
+    let mut tracer = trace::start(14, json!({"floor": floor}));
+    // the grid is shifted so the source is at its own x, the trace gives the puzzle's
+    let puzzle_point = |(x, y): (usize, usize)| (x as i32 - sand_source.0 + 500, y as i32);
+    loop {
+        // get sand location
+        let mut current_loc = (sand_source.0 as usize, sand_source.1 as usize);
+        let mut path = vec![puzzle_point(current_loc)];
+        loop {
+            if !floor && current_loc.1 == grid.len()-2 {
+                // sand fell of the edge of the universe
+                stop = true;
+                break;
+            }
+
+            // follow sand path
+            if (grid[current_loc.1][current_loc.0] == '.' || grid[current_loc.1][current_loc.0] == '+')
+                && (grid[current_loc.1+1][current_loc.0] == '#' || grid[current_loc.1+1][current_loc.0] == 'o')
+                && (grid[current_loc.1+1][current_loc.0-1] == '#' || grid[current_loc.1+1][current_loc.0-1] == 'o')
+                && (grid[current_loc.1+1][current_loc.0+1] == '#' || grid[current_loc.1+1][current_loc.0+1] == 'o')
+            {
+                // sand rests
+                grid[current_loc.1][current_loc.0] = 'o';
+                break;
+            } else {
+                // sand moves on
+                if grid[current_loc.1+1][current_loc.0] == '.' {
+                    // go down
+                    current_loc = (current_loc.0, current_loc.1+1);
+                } else if grid[current_loc.1+1][current_loc.0-1] == '.' {
+                    // go left
+                    current_loc = (current_loc.0-1, current_loc.1+1);
+                } else if grid[current_loc.1+1][current_loc.0+1] == '.' {
+                    // go right
+                    current_loc = (current_loc.0+1, current_loc.1+1);
+                } else {
+                    unreachable!("the squares below are rock, sand or air, and it didn't come to rest");
+                }
+                if tracer.is_some() {
+                    path.push(puzzle_point(current_loc));
+                }
+            }
+        }
+        if let Some(tracer) = tracer.as_mut() {
+            let rest = if stop { None } else { Some(puzzle_point(current_loc)) };
+            tracer.step(json!({"grain": total + 1, "path": path, "rest": rest}));
+        }
+
+        // animate
+        if renderer.is_active() {
+            renderer.frame(&draw_grid(grid));
+        }
+
+        if stop {
+            break;
+        }
+
+        total += 1;
+        if current_loc == (sand_source.0 as usize, sand_source.1 as usize) {
+            break;
+        }
+    }
+
+    return total;
+}
+
+
+// `size` rock paths of up to five axis-aligned segments, under the sand source and within reach of the sand
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let depth = (size.max(2) as i64 + 2).min(MAX_COORDINATE as i64);
+    let mut text = String::new();
+    for _ in 0..size.max(1) {
+        let mut point = (rng.range(500 - depth / 2, 500 + depth / 2 + 1), rng.range(2, depth + 1));
+        let mut points = vec![point];
+        let horizontal_first = rng.chance(0.5);
+        for segment in 0..rng.range(1, 6) {
+            let step = rng.range(1, 6) * if rng.chance(0.5) { 1 } else { -1 };
+            if (segment % 2 == 0) == horizontal_first {
+                point.0 = (point.0 + step).clamp(500 - depth / 2, 500 + depth / 2);
+            } else {
+                point.1 = (point.1 + step).clamp(2, depth);
+            }
+            points.push(point);
+        }
+        let path = points.iter().map(|(x, y)| format!("{},{}", x, y)).collect::<Vec<String>>();
+        text.push_str(&format!("{}\n", path.join(" -> ")));
+    }
+    return text;
+}
+
+
+fn lint_paths(input: &str) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let mut has_segment = false;
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        }
+        match parse_path(line_number, line) {
+            Ok(points) => {
+                has_segment |= points.len() > 1;
+                for end_span in diagonal_ends(&points) {
+                    let expected = "a point in line with the previous one";
+                    problems.push(ParseError::malformed(line_number, line, end_span, expected));
+                }
+            }
+            Err(problem) => problems.push(problem),
+        }
+    }
+    if !has_segment && problems.is_empty() {
+        problems.push(ParseError::missing(1, "a rock path"));
+    }
+    return problems;
+}
+
+
+// the cave once sand has filled it up to the source, for the report
+pub fn draw(input: &str, palette: &Palette) -> Result<Image, ParseError> {
+    let (mut grid, sand_source) = parse_grid(input)?;
+    process_sand(&mut grid, sand_source, true, &mut Headless);
+    return Ok(Image::from_chars(&grid, palette));
+}
+
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    type Input = Cave;
+    type Part1 = i32;
+    type Part2 = i32;
+
+    fn parse(input: &str) -> Result<Cave, ParseError> {
+        return parse_grid(input);
+    }
+
+    fn part1(input: &Cave) -> i32 {
+        let (grid, sand_source) = input;
+        return process_sand(&mut grid.clone(), *sand_source, false, &mut Headless);
+    }
+
+    fn part2(input: &Cave) -> i32 {
+        let (grid, sand_source) = input;
+        return process_sand(&mut grid.clone(), *sand_source, true, &mut Headless);
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        return lint_paths(input);
+    }
+}
@@ -0,0 +1,309 @@
+use std::collections::{HashSet, HashMap};
+use std::fmt;
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::cancel;
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::{info, debug, trace};
+use crate::input::{numbered_lines, parse_tuple_int};
+use crate::params::{Param, ParamSpec};
+use crate::solution::Solution;
+
+
+pub type Point = (i32, i32);
+
+// points shared by the boundaries of two sensor areas, keyed by the two sensors
+type AreaIntersections<'a> = HashMap<(&'a Point, &'a Point), HashSet<Point>>;
+
+fn get_sensor_range_for_row(sensor: &(i32, i32), beacon: &(i32, i32), search_row: &i32) -> Option<(i32, i32)> {
+    // determine where on the row the sensor searched for a beacon
+    let manhattan_dist = (sensor.0-beacon.0).abs() + (sensor.1-beacon.1).abs();
+    let sensor_dist = (sensor.1-search_row).abs();
+
+    if sensor_dist > manhattan_dist {
+        // no overlap possible
+        return None;
+    }
+
+    let sensor_span = manhattan_dist - sensor_dist;
+    return Some((sensor.0-sensor_span, sensor.0+sensor_span));
+}
+
+
+fn get_middle_points_for_sensor_group(
+    group: &[&(Point, Point)],
+    area_intersections_cache: &AreaIntersections
+) -> Vec<Point>
+{
+    // usually there's just 1 point, but areas whose edges run alongside each other can share several
+    let first_two_intersection = &area_intersections_cache[&(&group[0].0, &group[1].0)];
+    let last_two_intersection = &area_intersections_cache[&(&group[2].0, &group[3].0)];
+    return first_two_intersection
+        .intersection(last_two_intersection)
+        .copied()
+        .collect_vec();
+}
+
+// row the beacon-free positions are counted on, the example's is 10
+pub const SEARCH_ROW: Param<i32> = Param::new(15, "row", 2000000);
+
+// multiplier for the distress beacon's x in its tuning frequency
+pub const TUNING_MULTIPLIER: Param<i64> = Param::new(15, "tuning_multiplier", 4000000);
+
+// the distress beacon's x and y are between 0 and this, the example's is 20
+pub const SEARCH_LIMIT: Param<i32> = Param::new(15, "search_limit", 4000000).at_least(0);
+
+pub const PARAMS: &[ParamSpec] = &[SEARCH_ROW.spec(), TUNING_MULTIPLIER.spec(), SEARCH_LIMIT.spec()];
+
+
+fn parse_pairs(input: &str) -> Result<Vec<(Point, Point)>, ParseError> {
+    let mut sensor_beacon_pairs = Vec::new();
+    let re = Regex::new(
+        r"^Sensor at (?P<sensor>x=-?[0-9]+, y=-?[0-9]+): closest beacon is at (?P<beacon>x=-?[0-9]+, y=-?[0-9]+)$"
+    ).unwrap();
+    for (line_number, line) in numbered_lines(input) {
+        if line.is_empty() {
+            continue;
+        }
+        let caps = re.captures(line).ok_or_else(|| ParseError::malformed(
+            line_number, line, 0..line.len(), "\"Sensor at x=<x>, y=<y>: closest beacon is at x=<x>, y=<y>\""
+        ))?;
+
+        let mut points = Vec::new();
+        for name in ["sensor", "beacon"] {
+            let part = caps.name(name).unwrap();
+            points.push(parse_tuple_int(part.as_str()).ok_or_else(|| ParseError::malformed(line_number, line, part.range(), "a point"))?);
+        }
+
+        sensor_beacon_pairs.push((points[0], points[1]));
+    }
+    if sensor_beacon_pairs.is_empty() {
+        return Err(ParseError::missing(1, "a sensor"));
+    }
+    return Ok(sensor_beacon_pairs);
+}
+
+
+pub fn get_no_beacon_count(sensor_beacon_pairs: &[(Point, Point)], search_row: i32) -> i32 {
+    let mut max_x = i32::MIN;
+    let mut min_x = i32::MAX;
+    for (sensor, beacon) in sensor_beacon_pairs {
+        let manhattan_dist = (sensor.0-beacon.0).abs() + (sensor.1-beacon.1).abs();
+        if (sensor.0 + manhattan_dist) > max_x {
+            max_x = sensor.0 + manhattan_dist;
+        }
+        if (sensor.0 - manhattan_dist) < min_x {
+            min_x = sensor.0 - manhattan_dist;
+        }
+    }
+
+    let x_shift = -min_x;
+    max_x += x_shift;
+
+    let adj_x = |x: i32| -> usize { (x + x_shift) as usize };
+
+    let mut row_data = (0..=max_x).map(|_| '.').collect_vec();
+    for (sensor, beacon) in sensor_beacon_pairs {
+        if beacon.1 == search_row {
+            // beacon is on the row
+            row_data[adj_x(beacon.0)] = 'B';
+        }
+
+        match get_sensor_range_for_row(sensor, beacon, &search_row) {
+            None => continue,
+            Some(sensor_range) => {
+                // beacon range is inclusive
+                for c in &mut row_data[adj_x(sensor_range.0)..=adj_x(sensor_range.1)] {
+                    if *c != 'B' {
+                        *c = '#';
+                    }
+                }
+            }
+        }
+    }
+
+    let total = row_data.iter().fold(0, |acc,&x| if x == '#' { acc + 1 } else { acc });
+
+    return total;
+}
+
+
+// the tuning frequency of the one point in the search area no sensor covers, None when there's no such point
+// bounded by four sensors
+pub fn get_tuning_frequency(sensor_beacon_pairs: &[(Point, Point)], search_limit: i32, tuning_multiplier: i64) -> Option<i64> {
+    // areas
+    info!("finding the points just outside each sensor's area");
+    let mut sensor_area_points: HashMap<&(i32,i32), HashSet<(i32,i32)>> = HashMap::new();
+    let mut i = 0;
+    for (sensor, beacon) in sensor_beacon_pairs {
+        cancel::check(i, || format!("{} of {} sensors' boundaries found", i, sensor_beacon_pairs.len()));
+        i += 1;
+        debug!("sensor {} of {}", i, sensor_beacon_pairs.len());
+        let manhattan_dist = (sensor.0-beacon.0).abs() + (sensor.1-beacon.1).abs();
+
+        let mut sensor_points = HashSet::new();
+        for i in 0..=(manhattan_dist+1) {
+            // construct possible points all around the sensor area
+            sensor_points.extend(
+                [
+                    // 4 quadrants
+                    (sensor.0+i, sensor.1+((manhattan_dist+1)-i)),
+                    (sensor.0-i, sensor.1+((manhattan_dist+1)-i)),
+                    (sensor.0+i, sensor.1-((manhattan_dist+1)-i)),
+                    (sensor.0-i, sensor.1-((manhattan_dist+1)-i)),
+                ].iter()
+            )
+        }
+
+        sensor_area_points.insert(sensor, sensor_points);
+    }
+
+    // pre-seed intersections, this takes a while due to set size
+    info!("intersecting the areas of each pair of sensors");
+    let mut area_intersections_cache: AreaIntersections = HashMap::new();
+    let n = sensor_beacon_pairs.len() as u64;
+    let mut i = 0;
+    for group in sensor_beacon_pairs.iter().combinations(2) {
+        cancel::check(i, || format!("{} of {} pairs of sensors intersected", i, n * n.saturating_sub(1) / 2));
+        i += 1;
+        debug!("pair {}", i);
+        area_intersections_cache.insert(
+            (&group[0].0, &group[1].0),
+            sensor_area_points[&group[0].0]
+                .intersection(&sensor_area_points[&group[1].0])
+                .map(|x| x.to_owned())
+                .collect::<HashSet<(i32,i32)>>()
+        );
+    }
+
+    // now look at each group, get the middle point if there is one
+    info!("looking for a point between groups of four sensors");
+    let mut maybe_middle_point = None;
+    let mut i = 0;
+    let mut ruled_out = HashSet::new();
+    let group_count = n * n.saturating_sub(1) * n.saturating_sub(2) * n.saturating_sub(3) / 24;
+    for group in sensor_beacon_pairs.iter().combinations(4) {
+        cancel::check(i, || format!("{} of {} groups of four sensors looked at, {} points ruled out", i, group_count, ruled_out.len()));
+        i += 1;
+        trace!("group {}", i);
+        for p in get_middle_points_for_sensor_group(&group, &area_intersections_cache) {
+            // confirm that this is right
+            if ruled_out.contains(&p) {
+                continue;
+            } else if p.0 < 0 || p.1 < 0 || p.0 > search_limit || p.1 > search_limit {
+                ruled_out.insert(p);
+                continue;
+            } else {
+                let mut found_overlap = false;
+                for (sensor, beacon) in sensor_beacon_pairs {
+                    let sensor_beacon_dist = (sensor.0-beacon.0).abs() + (sensor.1-beacon.1).abs();
+                    let sensor_point_dist = (sensor.0-p.0).abs() + (sensor.1-p.1).abs();
+                    if sensor_beacon_dist >= sensor_point_dist {
+                        // a different sensor overlaps
+                        found_overlap = true;
+                        break;
+                    }
+                }
+                if found_overlap {
+                    ruled_out.insert(p);
+                    continue;
+                }
+
+            }
+            maybe_middle_point = Some(p);
+            info!("found the beacon at {:?} after {} groups", p, i);
+            break;
+        }
+        if maybe_middle_point.is_some() {
+            break;
+        }
+    }
+    if maybe_middle_point.is_none() {
+        info!("no point is left uncovered after {} groups", i);
+    }
+
+    return maybe_middle_point.map(|middle_point| (middle_point.0 as i64) * tuning_multiplier + (middle_point.1 as i64));
+}
+
+
+// `size` sensors, at least 4, leaving one point of the distress signal's search area uncovered like the
+// puzzle's: one in each corner of the area, reaching to next to the point from their side, and the rest
+// scattered around it. Every sensor's beacon is one of the point's neighbours, so each is the closest.
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let limit = SEARCH_LIMIT.default;
+    let distance = |a: Point, b: Point| (a.0 - b.0).abs() + (a.1 - b.1).abs();
+    let middle = limit as i64 / 4..limit as i64 * 3 / 4;
+    let gap = (rng.range(middle.start, middle.end) as i32, rng.range(middle.start, middle.end) as i32);
+    // the neighbour of the gap a step towards the sensor, as near to it as the sensor's area can reach
+    let beacon_for = |sensor: Point| {
+        if sensor.0 != gap.0 {
+            return (gap.0 + (sensor.0 - gap.0).signum(), gap.1);
+        }
+        return (gap.0, gap.1 + (sensor.1 - gap.1).signum());
+    };
+
+    let spread = limit as i64 / 40;
+    let mut sensors = Vec::new();
+    while sensors.len() + 4 < size {
+        let sensor = (gap.0 + rng.range(-spread, spread + 1) as i32, gap.1 + rng.range(-spread, spread + 1) as i32);
+        // a sensor next to the gap would have its beacon on top of it
+        if distance(sensor, gap) >= 2 {
+            sensors.push(sensor);
+        }
+    }
+    // each corner's sensor covers the rectangle between it and the gap, all but the gap itself
+    for corner in [(0, 0), (limit, 0), (0, limit), (limit, limit)] {
+        sensors.insert(rng.below(sensors.len() + 1), corner);
+    }
+
+    let mut text = String::new();
+    for sensor in sensors {
+        let beacon = beacon_for(sensor);
+        text.push_str(&format!(
+            "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", sensor.0, sensor.1, beacon.0, beacon.1
+        ));
+    }
+    return text;
+}
+
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+
+/// The distress beacon's tuning frequency, or that the sensors leave no point of the search area for it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TuningFrequency(pub Option<i64>);
+
+impl fmt::Display for TuningFrequency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self.0 {
+            Some(frequency) => write!(f, "{}", frequency),
+            None => write!(f, "no distress beacon"),
+        };
+    }
+}
+
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    type Input = Vec<(Point, Point)>;
+    type Part1 = i32;
+    type Part2 = TuningFrequency;
+
+    fn parse(input: &str) -> Result<Vec<(Point, Point)>, ParseError> {
+        return parse_pairs(input);
+    }
+
+    fn part1(sensor_beacon_pairs: &Vec<(Point, Point)>) -> i32 {
+        return get_no_beacon_count(sensor_beacon_pairs, SEARCH_ROW.get());
+    }
+
+    fn part2(sensor_beacon_pairs: &Vec<(Point, Point)>) -> TuningFrequency {
+        return TuningFrequency(get_tuning_frequency(sensor_beacon_pairs, SEARCH_LIMIT.get(), TUNING_MULTIPLIER.get()));
+    }
+}
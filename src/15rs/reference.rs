@@ -0,0 +1,61 @@
+// Slow, direct versions of the day's answers, for differential tests against the shortcuts above.
+use crate::generate::Rng;
+
+use super::{Point, TUNING_MULTIPLIER};
+
+
+fn distance(a: &Point, b: &Point) -> i32 {
+    return (a.0 - b.0).abs() + (a.1 - b.1).abs();
+}
+
+fn is_covered(sensor_beacon_pairs: &[(Point, Point)], point: &Point) -> bool {
+    return sensor_beacon_pairs.iter().any(|(sensor, beacon)| distance(sensor, point) <= distance(sensor, beacon));
+}
+
+/// Checks every position the sensors could reach on the row.
+pub fn get_no_beacon_count(sensor_beacon_pairs: &[(Point, Point)], search_row: i32) -> i32 {
+    let reach = sensor_beacon_pairs.iter().map(|(sensor, beacon)| distance(sensor, beacon)).max().unwrap();
+    let min_x = sensor_beacon_pairs.iter().map(|(sensor, _)| sensor.0).min().unwrap() - reach;
+    let max_x = sensor_beacon_pairs.iter().map(|(sensor, _)| sensor.0).max().unwrap() + reach;
+    return (min_x..=max_x)
+        .filter(|x| {
+            let point = (*x, search_row);
+            is_covered(sensor_beacon_pairs, &point) && !sensor_beacon_pairs.iter().any(|(_, beacon)| *beacon == point)
+        })
+        .count() as i32;
+}
+
+/// Checks every point of the search area, from 0 to `limit` both ways, and needs exactly one uncovered.
+pub fn get_tuning_frequency(sensor_beacon_pairs: &[(Point, Point)], limit: i32) -> Option<i64> {
+    let uncovered = (0..=limit)
+        .flat_map(|x| (0..=limit).map(move |y| (x, y)))
+        .filter(|point| !is_covered(sensor_beacon_pairs, point))
+        .collect::<Vec<Point>>();
+    return match uncovered[..] {
+        [point] => Some(point.0 as i64 * TUNING_MULTIPLIER.default + point.1 as i64),
+        _ => None,
+    };
+}
+
+/// A field like the example's: at least four sensors in a `limit` square that leave one point of it
+/// uncovered, the sensors' areas each reaching to next to the point. Gives up after `size` sensors.
+pub fn generate_small(rng: &mut Rng, size: usize, limit: i32) -> Option<String> {
+    let gap = (rng.range(0, limit as i64 + 1) as i32, rng.range(0, limit as i64 + 1) as i32);
+    let mut sensor_beacon_pairs: Vec<(Point, Point)> = Vec::new();
+    for _ in 0..size {
+        let sensor = (rng.range(0, limit as i64 + 1) as i32, rng.range(0, limit as i64 + 1) as i32);
+        let reach = distance(&sensor, &gap) - 1;
+        if reach < 1 {
+            continue;
+        }
+        let dx = rng.range(-reach as i64, reach as i64 + 1) as i32;
+        let dy = (reach - dx.abs()) * if rng.chance(0.5) { 1 } else { -1 };
+        sensor_beacon_pairs.push((sensor, (sensor.0 + dx, sensor.1 + dy)));
+        if sensor_beacon_pairs.len() >= 4 && get_tuning_frequency(&sensor_beacon_pairs, limit).is_some() {
+            let lines = sensor_beacon_pairs.iter()
+                .map(|(s, b)| format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", s.0, s.1, b.0, b.1));
+            return Some(lines.collect());
+        }
+    }
+    return None;
+}
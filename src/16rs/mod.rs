@@ -0,0 +1,369 @@
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::ops::Range;
+
+use itertools::Itertools;
+use regex::Regex;
+
+use crate::cancel;
+use crate::error::ParseError;
+use crate::generate::{Rng, UPPERCASE};
+use crate::input::numbered_lines;
+use crate::params::{Param, ParamSpec};
+use crate::solution::Solution;
+
+
+// minutes before the eruption, working alone and then with the elephant after teaching it
+pub const PART1_MINUTES: Param<i32> = Param::new(16, "part1_minutes", 30);
+pub const PART2_MINUTES: Param<i32> = Param::new(16, "part2_minutes", 26);
+
+pub const PARAMS: &[ParamSpec] = &[PART1_MINUTES.spec(), PART2_MINUTES.spec()];
+
+
+#[derive(PartialEq,Eq,Hash,Clone)]
+pub struct Valve {
+    name: String,
+    rate: i64,
+    adjacents: Vec<String>,
+}
+
+impl fmt::Debug for Valve {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+
+fn valve_pattern() -> Regex {
+    return Regex::new(
+        r"^Valve (?P<name>[A-Z]+) has flow rate=(?P<rate>[0-9]+); tunnel[s]? lead[s]? to valve[s]? (?P<adjacents>[A-Z]+(, [A-Z]+)*)$"
+    ).unwrap();
+}
+
+// a valve from its line of the scan, with where on the line each valve its tunnels lead to is named
+fn parse_valve(re: &Regex, line_number: usize, line: &str) -> Result<(Valve, Vec<Range<usize>>), ParseError> {
+    let caps = re.captures(line).ok_or_else(|| ParseError::malformed(
+        line_number, line, 0..line.len(), "\"Valve <name> has flow rate=<rate>; tunnels lead to valves <names>\""
+    ))?;
+    let rate_part = caps.name("rate").unwrap();
+    let adjacents_part = caps.name("adjacents").unwrap();
+    let mut adjacent_spans = Vec::new();
+    let mut start = adjacents_part.start();
+    for adjacent in adjacents_part.as_str().split(", ") {
+        adjacent_spans.push(start..start + adjacent.len());
+        start += adjacent.len() + ", ".len();
+    }
+    let valve = Valve {
+        name: caps.name("name").unwrap().as_str().to_string(),
+        rate: rate_part
+            .as_str()
+            .parse::<i64>()
+            .map_err(|_| ParseError::malformed(line_number, line, rate_part.range(), "a flow rate"))?,
+        adjacents: adjacent_spans.iter().map(|span| line[span.clone()].to_string()).collect_vec(),
+    };
+    return Ok((valve, adjacent_spans));
+}
+
+fn parse_valves(input: &str) -> Result<Vec<Valve>, ParseError> {
+    let mut result = Vec::new();
+    let mut adjacent_spans = Vec::new();
+    let re = valve_pattern();
+    for (line_number, line) in numbered_lines(input) {
+        if !line.is_empty() {
+            let (valve, spans) = parse_valve(&re, line_number, line)?;
+            result.push(valve);
+            adjacent_spans.push((line_number, line, spans));
+        }
+    }
+
+    // tunnels have to lead to valves that exist
+    for (valve, (line_number, line, spans)) in result.iter().zip(adjacent_spans) {
+        for span in spans {
+            if !result.iter().any(|v| v.name == line[span.clone()]) {
+                return Err(ParseError::malformed(line_number, line, span, "the name of a valve in the scan"));
+            }
+        }
+        if result.iter().filter(|v| v.name == valve.name).count() > 1 {
+            return Err(ParseError::malformed(line_number, line, 0..line.len(), &format!("a single scan of valve {}", valve.name)));
+        }
+    }
+    if !result.iter().any(|v| v.name == "AA") {
+        return Err(ParseError::missing(1, "a valve named AA to start from"));
+    }
+    return Ok(result);
+}
+
+
+// besides what the parser checks, the search starts from AA and only counts valves it can reach
+fn lint_valves(input: &str) -> Vec<ParseError> {
+    let mut problems = Vec::new();
+    let mut scanned = Vec::new();
+    let re = valve_pattern();
+    for (line_number, line) in numbered_lines(input) {
+        if !line.is_empty() {
+            match parse_valve(&re, line_number, line) {
+                Ok((valve, adjacent_spans)) => scanned.push((line_number, line, valve, adjacent_spans)),
+                Err(problem) => problems.push(problem),
+            }
+        }
+    }
+
+    let mut first_lines = HashMap::new();
+    for (line_number, line, valve, adjacent_spans) in &scanned {
+        for span in adjacent_spans {
+            if !scanned.iter().any(|(_, _, v, _)| v.name == line[span.clone()]) {
+                problems.push(ParseError::malformed(*line_number, line, span.clone(), "the name of a valve in the scan"));
+            }
+        }
+        if let Some(first_line) = first_lines.insert(valve.name.as_str(), *line_number) {
+            let expected = format!("a single scan of valve {}, already scanned on line {}", valve.name, first_line);
+            problems.push(ParseError::malformed(*line_number, line, 0..line.len(), &expected));
+            first_lines.insert(valve.name.as_str(), first_line);
+        }
+    }
+    // without a start there's nothing to be reachable from
+    let has_start = first_lines.contains_key("AA");
+    if !has_start {
+        problems.push(ParseError::missing(1, "a valve named AA to start from"));
+    }
+
+    let mut reached = HashSet::from(["AA"]);
+    let mut to_visit = vec!["AA"];
+    while let Some(name) = to_visit.pop() {
+        for (_, _, valve, _) in scanned.iter().filter(|(_, _, v, _)| v.name == name) {
+            for adjacent in &valve.adjacents {
+                if reached.insert(adjacent.as_str()) {
+                    to_visit.push(adjacent.as_str());
+                }
+            }
+        }
+    }
+    for (line_number, line, valve, _) in &scanned {
+        if valve.rate > 0 && !reached.contains(valve.name.as_str()) && has_start {
+            let name_span = "Valve ".len().."Valve ".len() + valve.name.len();
+            problems.push(ParseError::malformed(*line_number, line, name_span, "a valve with flow reachable from AA"));
+        }
+    }
+    problems.sort_by_key(|problem| problem.line());
+    return problems;
+}
+
+
+fn get_shortest_path(valves: &[Valve], start: &Valve, end: &Valve) -> i32 {
+    // dijstra shortest distance between 2 valves
+    let mut distances = HashMap::new();
+    let mut shortest_path_tree_set = HashSet::new();
+
+    for valve in valves {
+        if valve.name == start.name {
+            distances.insert(valve, 0);
+        } else {
+            distances.insert(valve, i32::MAX);
+        }
+    }
+
+    loop {
+        if shortest_path_tree_set.len() == valves.len() {
+            break;
+        }
+
+        let current_valve = *distances
+            .iter()
+            .filter(|(&v, &_d)| !shortest_path_tree_set.contains(&v))
+            .min_by_key(|(&_v,&d)| d)
+            .unwrap()
+            .0;
+
+        shortest_path_tree_set.insert(current_valve);
+
+        for adjacent in current_valve.adjacents.iter() {
+            let adjacent_valve = valves.iter().find(|v| &v.name == adjacent).unwrap();
+
+            let mut new_dist = -1;
+            if distances[&current_valve] < i32::MAX && distances[&current_valve] + 1 < distances[&adjacent_valve] {
+                new_dist = distances[&current_valve] + 1;
+            }
+            if new_dist > -1 {
+                distances.insert(adjacent_valve, new_dist);
+            }
+        }
+    }
+
+    return distances[end];
+}
+
+
+fn get_cached_shortest_path(
+    shortest_paths_cache: &mut HashMap<(Valve, Valve), i64>,
+    valves: &[Valve],
+    source: &Valve,
+    target: &Valve,
+) -> i64 {
+    return *shortest_paths_cache
+        .entry((source.clone(), target.clone()))
+        .or_insert_with(|| get_shortest_path(valves, source, target) as i64);
+}
+
+
+// what stays the same through the search for the best order to open the valves in, and the most pressure
+// found for each set of valves opened
+struct ValveSearch {
+    // AA, then the valves worth opening
+    rates: Vec<i64>,
+    distances: Vec<Vec<i64>>,
+    // keyed by the valves opened, in index order
+    best: HashMap<Vec<usize>, i64>,
+    steps: u64,
+}
+
+impl ValveSearch {
+    fn new(valves: &[Valve]) -> Self {
+        let mut shortest_paths_cache = HashMap::new();
+
+        let start = valves.iter().find(|v| v.name == "AA").unwrap();
+        let mut targets = vec![start];
+        targets.extend(valves.iter().filter(|v| v.rate > 0));
+
+        let rates = targets.iter().map(|v| v.rate).collect_vec();
+        let distances = targets
+            .iter()
+            .map(|&source| {
+                targets
+                    .iter()
+                    .map(|&target| get_cached_shortest_path(&mut shortest_paths_cache, valves, source, target))
+                    .collect_vec()
+            })
+            .collect_vec();
+        return ValveSearch { rates, distances, best: HashMap::new(), steps: 0 };
+    }
+
+    // tries every order of opening the valves still closed from `current` on, skipping those that run out of
+    // time, `opened` and `pressure` being what it took to get here
+    fn explore(&mut self, current: usize, minutes_left: i64, opened: &mut Vec<usize>, pressure: i64) {
+        cancel::check(self.steps, || format!(
+            "{} sets of valves opened, the best releasing {}",
+            self.best.len(), self.best.values().max().unwrap_or(&0)
+        ));
+        self.steps += 1;
+        match self.best.get_mut(opened.as_slice()) {
+            Some(best) => *best = (*best).max(pressure),
+            None => {
+                self.best.insert(opened.clone(), pressure);
+            }
+        }
+
+        for target in 1..self.rates.len() {
+            if let Err(i) = opened.binary_search(&target) {
+                // walk there and open it
+                let target_minutes_left = minutes_left - self.distances[current][target] - 1;
+                if target_minutes_left > 0 {
+                    opened.insert(i, target);
+                    self.explore(target, target_minutes_left, opened, pressure + self.rates[target] * target_minutes_left);
+                    opened.remove(i);
+                }
+            }
+        }
+    }
+}
+
+// the most pressure each set of valves can release, opened alone from AA
+fn get_best_by_opened(valves: &[Valve], minutes: i32) -> HashMap<Vec<usize>, i64> {
+    let mut search = ValveSearch::new(valves);
+    search.explore(0, minutes as i64, &mut Vec::new(), 0);
+    return search.best;
+}
+
+
+pub fn find_best_solo_pressure_sum(valves: &[Valve], minutes: i32) -> i64 {
+    // without the elephant every order of the working valves can be tried
+    return *get_best_by_opened(valves, minutes).values().max().unwrap();
+}
+
+
+pub fn find_best_path_pressure_sum(valves: &[Valve], minutes: i32) -> i64 {
+    // the elephant and I open separate sets of valves, each as if alone, so the best is the best pair of
+    // disjoint sets; going from the most pressure down, a pair can stop as soon as it can't beat the best yet
+    let best = get_best_by_opened(valves, minutes).into_iter().sorted_by_key(|(_, pressure)| -pressure).collect_vec();
+    let mut most = 0;
+    for (i, (mine, my_pressure)) in best.iter().enumerate() {
+        if my_pressure * 2 <= most {
+            break;
+        }
+        for (elephants, elephant_pressure) in &best[i..] {
+            if my_pressure + elephant_pressure <= most {
+                break;
+            }
+            if !mine.iter().any(|valve| elephants.binary_search(valve).is_ok()) {
+                most = my_pressure + elephant_pressure;
+            }
+        }
+    }
+    return most;
+}
+
+
+// `size` valves (two to 676) in a connected network of tunnels, about a third of them with a working flow rate
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let count = size.clamp(2, 26 * 26);
+    let mut names = UPPERCASE.chars()
+        .flat_map(|a| UPPERCASE.chars().map(move |b| format!("{}{}", a, b)))
+        .filter(|name| name != "AA")
+        .collect::<Vec<String>>();
+    rng.shuffle(&mut names);
+    names.insert(0, "AA".to_string());
+
+    // a spanning tree, then a few shortcuts
+    let mut tunnels = vec![Vec::new(); count];
+    fn connect(tunnels: &mut [Vec<usize>], a: usize, b: usize) {
+        if a != b && !tunnels[a].contains(&b) {
+            tunnels[a].push(b);
+            tunnels[b].push(a);
+        }
+    }
+    for valve in 1..count {
+        let other = rng.below(valve);
+        connect(&mut tunnels, valve, other);
+    }
+    for _ in 0..count / 2 {
+        let (a, b) = (rng.below(count), rng.below(count));
+        connect(&mut tunnels, a, b);
+    }
+
+    let mut text = String::new();
+    for (valve, adjacents) in tunnels.iter().enumerate() {
+        let rate = if valve > 0 && rng.chance(0.35) { rng.range(1, 26) } else { 0 };
+        let adjacents = adjacents.iter().map(|i| names[*i].as_str()).collect::<Vec<&str>>();
+        let tunnels = if adjacents.len() == 1 { "tunnel leads to valve" } else { "tunnels lead to valves" };
+        text.push_str(&format!("Valve {} has flow rate={}; {} {}\n", names[valve], rate, tunnels, adjacents.join(", ")));
+    }
+    return text;
+}
+
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    type Input = Vec<Valve>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<Valve>, ParseError> {
+        return parse_valves(input);
+    }
+
+    fn part1(valves: &Vec<Valve>) -> i64 {
+        return find_best_solo_pressure_sum(valves, PART1_MINUTES.get());
+    }
+
+    fn part2(valves: &Vec<Valve>) -> i64 {
+        return find_best_path_pressure_sum(valves, PART2_MINUTES.get());
+    }
+
+    fn lint(input: &str) -> Vec<ParseError> {
+        return lint_valves(input);
+    }
+}
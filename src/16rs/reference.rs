@@ -0,0 +1,75 @@
+// Slow, direct versions of the day's answers, for differential tests against the shortcuts above.
+use std::collections::HashMap;
+
+use super::Valve;
+
+
+// every choice every minute: each walker opens the valve it's at, walks down one of its tunnels or waits
+struct Search<'a> {
+    valves: &'a [Valve],
+    adjacents: Vec<Vec<usize>>,
+    // the most pressure still to come from each minute, walkers' valves and valves opened
+    best: HashMap<(i32, Vec<usize>, u64), i64>,
+}
+
+impl Search<'_> {
+    fn new(valves: &[Valve]) -> Search<'_> {
+        assert!(valves.len() <= 64, "the reference keeps opened valves in a u64");
+        let index = valves.iter().enumerate().map(|(i, v)| (v.name.as_str(), i)).collect::<HashMap<&str, usize>>();
+        let adjacents = valves.iter().map(|v| v.adjacents.iter().map(|a| index[a.as_str()]).collect()).collect();
+        return Search { valves, adjacents, best: HashMap::new() };
+    }
+
+    // the most pressure the walkers at `at` can still release with `minutes_left`
+    fn best(&mut self, minutes_left: i32, mut at: Vec<usize>, opened: u64) -> i64 {
+        if minutes_left == 0 {
+            return 0;
+        }
+        // the walkers are alike, so which is where doesn't matter
+        at.sort();
+        let key = (minutes_left, at, opened);
+        if let Some(best) = self.best.get(&key) {
+            return *best;
+        }
+        let best = self.minute(minutes_left, &key.1, Vec::new(), opened, 0);
+        self.best.insert(key, best);
+        return best;
+    }
+
+    // the walkers' choices for this minute, from the walker `next.len()` on
+    fn minute(&mut self, minutes_left: i32, at: &[usize], next: Vec<usize>, opened: u64, released: i64) -> i64 {
+        let walker = next.len();
+        if walker == at.len() {
+            return released + self.best(minutes_left - 1, next, opened);
+        }
+        let here = at[walker];
+        let with = |valve: usize| [next.as_slice(), &[valve]].concat();
+
+        let mut most = self.minute(minutes_left, at, with(here), opened, released);
+        if self.valves[here].rate > 0 && opened & (1 << here) == 0 {
+            // open from the end of this minute on
+            let pressure = self.valves[here].rate * (minutes_left - 1) as i64;
+            most = most.max(self.minute(minutes_left, at, with(here), opened | (1 << here), released + pressure));
+        }
+        for adjacent in self.adjacents[here].clone() {
+            most = most.max(self.minute(minutes_left, at, with(adjacent), opened, released));
+        }
+        return most;
+    }
+}
+
+// the most pressure `walkers` of you starting together at AA can release
+fn find_best(valves: &[Valve], minutes: i32, walkers: usize) -> i64 {
+    let start = valves.iter().position(|v| v.name == "AA").unwrap();
+    return Search::new(valves).best(minutes, vec![start; walkers], 0);
+}
+
+/// Tries every move every minute.
+pub fn find_best_solo_pressure_sum(valves: &[Valve], minutes: i32) -> i64 {
+    return find_best(valves, minutes, 1);
+}
+
+/// Tries every move every minute for you and the elephant together.
+pub fn find_best_path_pressure_sum(valves: &[Valve], minutes: i32) -> i64 {
+    return find_best(valves, minutes, 2);
+}
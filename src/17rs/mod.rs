@@ -0,0 +1,499 @@
+use std::cmp;
+use std::collections::HashMap;
+use std::ops::RangeInclusive;
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::cancel;
+use crate::checkpoint::{self, Fingerprint};
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::image::{Image, Palette};
+use crate::{info, debug};
+use crate::input::numbered_lines;
+use crate::params::{Param, ParamSpec};
+use crate::render::{Headless, LastFrame, Renderer};
+use crate::solution::Solution;
+use crate::trace;
+
+pub struct Rock {
+    space: Vec<RangeInclusive<i32>>,
+    height: i32,
+    width: i32,
+}
+
+pub fn get_rocks() -> Vec<Rock> {
+    return vec![
+        Rock {
+            space: vec![0..=3],
+            height: 1,
+            width: 4,
+        },
+        Rock {
+            space: vec![1..=1, 0..=2, 1..=1],
+            height: 3,
+            width: 3,
+        },
+        Rock {
+            space: vec![2..=2, 2..=2, 0..=2],
+            height: 3,
+            width: 3,
+        },
+        Rock {
+            space: vec![0..=0, 0..=0, 0..=0, 0..=0],
+            height: 4,
+            width: 1,
+        },
+        Rock {
+            space: vec![0..=1, 0..=1],
+            height: 2,
+            width: 2,
+        },
+    ];
+}
+
+// rows at the top of the tower compared when looking for a repeating pattern
+const CYCLE_ROWS: usize = 40;
+
+// (rock index, jet index, top rows of the tower)
+type TowerState = (usize, usize, Vec<Vec<RangeInclusive<i32>>>);
+
+// what the rock loop carries from one rock to the next, saved so a long run can be resumed
+#[derive(Serialize, Deserialize)]
+struct TowerCheckpoint {
+    rocks_dropped: i64,
+    jet_index: usize,
+    rock_boundaries: Vec<Vec<RangeInclusive<i32>>>,
+    height: i64,
+    height_adjust: i64,
+    skipped_height: i64,
+    seen_states: Vec<(TowerState, (i64, i64))>,
+}
+
+fn apply_jet(
+    rock_boundaries: &[Vec<RangeInclusive<i32>>],
+    rock: &Rock,
+    rock_coords: &(i32, i64),
+    jet_dir: &char,
+    cavern_width: i32,
+) -> (i32, i64) {
+    let new_coords = match jet_dir {
+        '<' => (rock_coords.0 - 1, rock_coords.1),
+        '>' => (rock_coords.0 + 1, rock_coords.1),
+        _ => unreachable!("parse only lets < and > through"),
+    };
+
+    // test overlap
+    let mut overlaps = false;
+
+    if new_coords.0 < 0 || (new_coords.0 + rock.width) > cavern_width {
+        // overlaps edge
+        overlaps = true;
+    } else {
+        for (i, rock_row) in rock.space.iter().rev().enumerate() {
+            if let Some(rock_boundary_row) = rock_boundaries.get((new_coords.1 as usize) + i) {
+                for rock_boundary_segment in rock_boundary_row {
+                    if (new_coords.0 + rock_row.start()) <= *rock_boundary_segment.end()
+                        && (new_coords.0 + rock_row.end()) >= *rock_boundary_segment.start()
+                    {
+                        overlaps = true;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    if overlaps {
+        return *rock_coords;
+    } else {
+        return new_coords;
+    }
+}
+
+fn apply_gravity(
+    rock_boundaries: &[Vec<RangeInclusive<i32>>],
+    rock: &Rock,
+    rock_coords: &(i32, i64),
+) -> (i32, i64) {
+    let new_coords = (rock_coords.0, rock_coords.1 - 1);
+
+    // test overlap
+    let mut overlaps = false;
+
+    if new_coords.1 < 0 {
+        // overlaps bottom
+        overlaps = true;
+    } else {
+        for (i, rock_row) in rock.space.iter().rev().enumerate() {
+            if let Some(rock_boundary_row) = rock_boundaries.get((new_coords.1 as usize) + i) {
+                for rock_boundary_segment in rock_boundary_row {
+                    if (new_coords.0 + rock_row.start()) <= *rock_boundary_segment.end()
+                        && (new_coords.0 + rock_row.end()) >= *rock_boundary_segment.start()
+                    {
+                        overlaps = true;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    if overlaps {
+        return *rock_coords;
+    } else {
+        return new_coords;
+    }
+}
+
+fn draw_tower(
+    rock_boundaries: &[Vec<RangeInclusive<i32>>],
+    cavern_width: i32,
+    rock_coords: &(i32, i64),
+    rock: &Rock,
+) -> String {
+    let mut frame = String::new();
+
+    for (n, rock_boundary_row) in rock_boundaries.iter().rev().enumerate() {
+        let rock_boundary_y = rock_boundaries.len() - n - 1;
+        let mut in_new_rock_row = false;
+        if rock_boundary_y >= (rock_coords.1 as usize)
+            && rock_boundary_y <= ((rock_coords.1 as usize) + rock.space.len() - 1)
+        {
+            in_new_rock_row = true;
+        }
+        for i in 0..cavern_width {
+            let mut rock_state = "air";
+            if in_new_rock_row {
+                let rock_space_range = &rock.space
+                    [rock.space.len() - 1 - (rock_boundary_y - (rock_coords.1 as usize))];
+                if i >= (rock_coords.0 + rock_space_range.start())
+                    && i <= (rock_coords.0 + rock_space_range.end())
+                {
+                    rock_state = "new_rock"
+                }
+            }
+            for segment in rock_boundary_row {
+                if i >= *segment.start() && i <= *segment.end() {
+                    rock_state = "rock";
+                    break;
+                }
+            }
+            if rock_state == "rock" {
+                frame.push('#');
+            } else if rock_state == "new_rock" {
+                frame.push('@');
+            } else {
+                frame.push('.');
+            }
+        }
+        frame.push('\n');
+    }
+
+    // floor
+    frame.push_str(&"¯".repeat(cavern_width as usize));
+    frame.push('\n');
+    return frame;
+}
+
+fn merge_row_segments(
+    rock_boundary_row: &[RangeInclusive<i32>],
+    new_rock_range: &RangeInclusive<i32>,
+) -> Vec<RangeInclusive<i32>> {
+    // merge segment ranges within current row, which helps reduce future range computations and helps in
+    // determining when a range extends the entire width, and add in the new range; assumes the segment
+    // ranges are already sorted within the row
+    let mut new_row: Vec<RangeInclusive<i32>> = Vec::new();
+    let mut rock_boundary_row_iter = rock_boundary_row.iter();
+    let mut segment_added = false;
+    let mut segment_index = 0;
+    loop {
+        let mut maybe_current_segment = rock_boundary_row_iter.next();
+
+        // consider adding the new segment if not already added
+        if !segment_added {
+            match maybe_current_segment {
+                None => {
+                    // at the end of the row, process new segment now
+                    maybe_current_segment = Some(new_rock_range);
+                    segment_added = true;
+                }
+                Some(s) => {
+                    if new_rock_range.start() < s.start() {
+                        // process new segment instead of current segment
+                        maybe_current_segment = Some(new_rock_range);
+                        segment_added = true;
+                        rock_boundary_row_iter = rock_boundary_row.iter();
+                        if segment_index != 0 {
+                            // reset iter to be at the previous segment
+                            rock_boundary_row_iter.nth(segment_index - 1);
+                        }
+                    }
+                }
+            }
+        }
+        if maybe_current_segment != Some(new_rock_range) {
+            segment_index += 1;
+        }
+
+        // check if this segment is contiguous with previous, and add segment(s) to a new row
+        match maybe_current_segment {
+            None => break,
+            Some(current_segment) => {
+                if !new_row.is_empty() {
+                    let prev_segment_index = new_row.len() - 1;
+                    let prev_segment = &new_row[prev_segment_index];
+                    if *current_segment.start() == prev_segment.end() + 1 {
+                        // contiguous
+                        new_row[prev_segment_index] =
+                            *prev_segment.start()..=*current_segment.end();
+                    } else {
+                        new_row.push(current_segment.clone());
+                    }
+                } else {
+                    new_row.push(current_segment.clone());
+                }
+            }
+        }
+    }
+    return new_row;
+}
+
+// rocks dropped for each part, and for the animation
+pub const PART1_ROCKS: Param<i64> = Param::new(17, "part1_rocks", 2022);
+pub const PART2_ROCKS: Param<i64> = Param::new(17, "part2_rocks", 1000000000000);
+pub const ANIMATION_ROCKS: Param<i64> = Param::new(17, "animation_rocks", 20);
+
+// room for the widest rock, four across, two in from the left wall at least
+pub const CAVERN_WIDTH: Param<i32> = Param::new(17, "width", 7).at_least(6);
+
+pub const PARAMS: &[ParamSpec] = &[PART1_ROCKS.spec(), PART2_ROCKS.spec(), ANIMATION_ROCKS.spec(), CAVERN_WIDTH.spec()];
+
+pub fn get_rock_tower_height(
+    rocks: &[Rock],
+    jet_pattern: &[char],
+    rock_count: i64,
+    cavern_width: i32,
+    renderer: &mut dyn Renderer,
+) -> i64 {
+    // determine resultant rock tower height from rocks and their movements;
+    // simulating runs at 13 million rocks per minute (that's 59 days for 1 trillion rocks), so once the rock,
+    // the jet and the top of the tower line up with an earlier rock, the repeating cycles are skipped over
+    let rock_start_x_buffer = 2;
+    let widest_rock = rocks.iter().map(|r| r.width).max().unwrap_or(0);
+    assert!(
+        cavern_width >= rock_start_x_buffer + widest_rock,
+        "a cavern {} wide can't fit a rock {} wide {} from the wall", cavern_width, widest_rock, rock_start_x_buffer
+    );
+    let rock_start_y_buffer = 3i64;
+    let largest_rock_height = rocks.iter().fold(0, |acc,r| cmp::max(acc,r.height)) as i64;
+
+    // ranges representing where rocks-at-rest are
+    let mut rock_boundaries = Vec::new();
+
+    // seed rows for the size of the largest rock
+    // (plus the space between the highest rock and the starting point of the next rock,
+    // for display purposes)
+    for _ in 0..(largest_rock_height + rock_start_y_buffer) {
+        rock_boundaries.push(Vec::new());
+    }
+
+    let mut height_adjust = 0;
+    let mut height = 0;
+    let mut jet_index = 0;
+    let mut seen_states: HashMap<TowerState, (i64, i64)> = HashMap::new();
+    let mut skipped_height = 0;
+    let mut rc = 0;
+
+    let mut fingerprint = Fingerprint::new()
+        .text(&jet_pattern.iter().collect::<String>())
+        .number(rock_count)
+        .number(cavern_width as i64);
+    for rock in rocks {
+        fingerprint = fingerprint.number(rock.space.len() as i64);
+        for row in &rock.space {
+            fingerprint = fingerprint.number(*row.start() as i64).number(*row.end() as i64);
+        }
+    }
+    let mut checkpoint = checkpoint::open(&format!("day17-{}-rocks", rock_count), fingerprint.finish());
+    if let Some(saved) = checkpoint.as_ref().and_then(|c| c.resume::<TowerCheckpoint>()) {
+        rc = saved.rocks_dropped;
+        jet_index = saved.jet_index;
+        rock_boundaries = saved.rock_boundaries;
+        height = saved.height;
+        height_adjust = saved.height_adjust;
+        skipped_height = saved.skipped_height;
+        seen_states = saved.seen_states.into_iter().collect();
+    }
+    let mut tracer = trace::start(17, json!({"rocks": rock_count, "width": cavern_width, "resumed_at": rc}));
+    info!("dropping {} rocks", rock_count);
+    while rc < rock_count {
+        cancel::check(rc as u64, || format!("{} of {} rocks dropped, the tower {} high", rc, rock_count, height + skipped_height));
+        if rc > 0 && rc % 1000000000 == 0 {
+            debug!("dropped {} rocks", rc);
+        }
+        let rock_index = (rc as usize) % rocks.len();
+        let current_rock = &rocks[rock_index];
+        let mut rock_coords = (rock_start_x_buffer, (height - height_adjust + rock_start_y_buffer));
+        if renderer.is_active() {
+            renderer.frame(&draw_tower(&rock_boundaries, cavern_width, &rock_coords, current_rock));
+        }
+        // the jets that pushed the rock, and where it was after each push and fall, from the cavern floor
+        let mut jets = String::new();
+        let mut path = Vec::new();
+        loop {
+            let jet_dir = &jet_pattern[jet_index];
+            jet_index = (jet_index + 1) % jet_pattern.len();
+            rock_coords = apply_jet(
+                &rock_boundaries,
+                current_rock,
+                &rock_coords,
+                jet_dir,
+                cavern_width,
+            );
+
+            let new_rock_coords = apply_gravity(&rock_boundaries, current_rock, &rock_coords);
+            if renderer.is_active() {
+                renderer.frame(&draw_tower(&rock_boundaries, cavern_width, &new_rock_coords, current_rock));
+            }
+            if tracer.is_some() {
+                jets.push(*jet_dir);
+                path.push((new_rock_coords.0, new_rock_coords.1 + height_adjust));
+            }
+
+            if new_rock_coords == rock_coords {
+                break;
+            } else {
+                rock_coords = new_rock_coords;
+            }
+        }
+        if let Some(tracer) = tracer.as_mut() {
+            tracer.step(json!({"rock": rc + 1, "shape": rock_index, "jets": jets, "path": path}));
+        }
+
+        // add rock to rock boundaries, merge segments within each row
+        let mut all_rock_index = 0;
+        for (i, rock_row) in current_rock.space.iter().rev().enumerate() {
+            let rock_boundary_row = rock_boundaries
+                .get_mut((rock_coords.1 as usize) + i)
+                .unwrap();
+            let new_rock_range =
+                (rock_coords.0 + rock_row.start())..=(rock_coords.0 + rock_row.end());
+            let new_rock_boundary_row = merge_row_segments(rock_boundary_row, &new_rock_range);
+
+            // if row is all rocks, then cache the highest index
+            if new_rock_boundary_row.len() == 1
+                && new_rock_boundary_row[0] == (0..=cavern_width - 1)
+            {
+                all_rock_index = (rock_coords.1 as usize) + i;
+            }
+
+            rock_boundaries[(rock_coords.1 as usize) + i] = new_rock_boundary_row;
+        }
+
+        // cut off the rows below the rock wall, memory optimization
+        if all_rock_index > 0 {
+            rock_boundaries = rock_boundaries[all_rock_index + 1..].to_vec();
+            height_adjust += (all_rock_index + 1) as i64;
+            rock_coords = (rock_coords.0, rock_coords.1 - (all_rock_index + 1) as i64);
+        }
+
+        // adjust tower height if changed
+        let rock_height = height_adjust + rock_coords.1 + (current_rock.height as i64);
+        if rock_height > height {
+            // seed more boundary rows to account for the new height (the initial state is pre-seeded)
+            if height > 0 {
+                for _ in 0..(rock_height - height) {
+                    rock_boundaries.push(Vec::new());
+                }
+            }
+
+            height = rock_height;
+        }
+        rc += 1;
+
+        // look for a repeat of the current state, only needed until the first one is skipped over; a tower
+        // cut down to fewer rows than that is compared whole, nothing falls past a full row
+        if skipped_height == 0 {
+            let top = (height - height_adjust) as usize;
+            let top_rows = rock_boundaries[top.saturating_sub(CYCLE_ROWS)..top].to_vec();
+            if let Some((cycle_start_rc, cycle_start_height)) = seen_states.insert((rock_index, jet_index, top_rows), (rc, height)) {
+                let cycle_length = rc - cycle_start_rc;
+                let cycles = (rock_count - rc) / cycle_length;
+                skipped_height = cycles * (height - cycle_start_height);
+                info!("rocks {} to {} repeat, skipping {} cycles", cycle_start_rc, rc, cycles);
+                rc += cycles * cycle_length;
+                // only needed until now, and no longer worth saving
+                seen_states.clear();
+            }
+        }
+
+        // saved whenever it's due, and once the last rock is down
+        if let Some(checkpoint) = checkpoint.as_mut().filter(|c| rc >= rock_count || c.due()) {
+            checkpoint.save(&TowerCheckpoint {
+                rocks_dropped: rc,
+                jet_index,
+                rock_boundaries: rock_boundaries.clone(),
+                height,
+                height_adjust,
+                skipped_height,
+                seen_states: seen_states.iter().map(|(state, seen)| (state.clone(), *seen)).collect(),
+            });
+        }
+    }
+    return height + skipped_height;
+}
+
+
+// `size` jets
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    let jets = (0..size.max(1)).map(|_| if rng.chance(0.5) { '<' } else { '>' }).collect::<String>();
+    return format!("{}\n", jets);
+}
+
+
+#[cfg(feature = "reference")]
+pub mod reference;
+
+
+// rows of the tower's top the report shows
+const TOWER_TOP_ROWS: usize = 40;
+
+// the top of the tower after part 1's rocks, the last of them still marked as falling, for the report
+pub fn draw(input: &str, palette: &Palette) -> Result<Image, ParseError> {
+    let mut last_frame = LastFrame::default();
+    get_rock_tower_height(&get_rocks(), &Day17::parse(input)?, PART1_ROCKS.get(), CAVERN_WIDTH.get(), &mut last_frame);
+    let frame = last_frame.frame.unwrap_or_default();
+    return Ok(Image::from_text(&frame.lines().take(TOWER_TOP_ROWS).join("\n"), palette));
+}
+
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    type Input = Vec<char>;
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Vec<char>, ParseError> {
+        match numbered_lines(input).first() {
+            Some(&(line_number, line)) if !line.is_empty() => {
+                if let Some((i, c)) = line.char_indices().find(|(_, c)| *c != '<' && *c != '>') {
+                    return Err(ParseError::malformed(line_number, line, i..i + c.len_utf8(), "a jet, < or >"));
+                }
+                return Ok(line.chars().collect_vec());
+            }
+            _ => return Err(ParseError::missing(1, "a jet pattern")),
+        }
+    }
+
+    fn part1(jet_pattern: &Vec<char>) -> i64 {
+        return get_rock_tower_height(&get_rocks(), jet_pattern, PART1_ROCKS.get(), CAVERN_WIDTH.get(), &mut Headless);
+    }
+
+    fn part2(jet_pattern: &Vec<char>) -> i64 {
+        return get_rock_tower_height(&get_rocks(), jet_pattern, PART2_ROCKS.get(), CAVERN_WIDTH.get(), &mut Headless);
+    }
+}
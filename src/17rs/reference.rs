@@ -0,0 +1,44 @@
+// Slow, direct versions of the day's answers, for differential tests against the shortcuts above.
+use super::Rock;
+
+
+// whether the rock fits with its bottom left corner at (x, y), within the walls, above the floor and clear of
+// the rocks at rest
+fn fits(cells: &[Vec<bool>], rock: &Rock, x: i32, y: i64, cavern_width: i32) -> bool {
+    if x < 0 || x + rock.width > cavern_width || y < 0 {
+        return false;
+    }
+    return rock.space.iter().rev().enumerate().all(|(i, row)| {
+        row.clone().all(|dx| cells.get(y as usize + i).is_none_or(|cells_row| !cells_row[(x + dx) as usize]))
+    });
+}
+
+/// Drops every rock into a grid of cells one at a time, no rows cut off and no cycles skipped.
+pub fn get_rock_tower_height(rocks: &[Rock], jet_pattern: &[char], rock_count: i64, cavern_width: i32) -> i64 {
+    // the rows of the tower from the floor up, whether each cell is rock
+    let mut cells: Vec<Vec<bool>> = Vec::new();
+    let mut jets = jet_pattern.iter().cycle();
+    for rock in rocks.iter().cycle().take(rock_count as usize) {
+        let (mut x, mut y) = (2, cells.len() as i64 + 3);
+        loop {
+            let pushed = if *jets.next().unwrap() == '<' { x - 1 } else { x + 1 };
+            if fits(&cells, rock, pushed, y, cavern_width) {
+                x = pushed;
+            }
+            if !fits(&cells, rock, x, y - 1, cavern_width) {
+                break;
+            }
+            y -= 1;
+        }
+        for (i, row) in rock.space.iter().rev().enumerate() {
+            let row_index = y as usize + i;
+            if row_index >= cells.len() {
+                cells.resize(row_index + 1, vec![false; cavern_width as usize]);
+            }
+            for dx in row.clone() {
+                cells[row_index][(x + dx) as usize] = true;
+            }
+        }
+    }
+    return cells.len() as i64;
+}
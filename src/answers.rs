@@ -0,0 +1,46 @@
+use std::path::Path;
+
+use toml::{Table, Value};
+
+use crate::input::read_to_string;
+
+
+/// Accepted answers for the real inputs, read from a TOML file with a table per day:
+///
+/// ```toml
+/// [01]
+/// part1 = "74394"
+/// part2 = "212836"
+/// ```
+pub struct Answers {
+    table: Table,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, String> {
+        return Ok(Answers { table: load_table(path)? });
+    }
+
+    /// The answers for one input of a day, from a file holding the day's table alone, see
+    /// `batch::ANSWER_EXTENSION`.
+    pub fn load_input_answers(path: &Path, day: u32) -> Result<Self, String> {
+        let mut table = Table::new();
+        table.insert(format!("{:02}", day), Value::Table(load_table(path)?));
+        return Ok(Answers { table });
+    }
+
+    /// The recorded answer for a day's part, if there is one. Integers and strings are both accepted.
+    pub fn get(&self, day: u32, part: u32) -> Option<String> {
+        let value = self.table.get(&format!("{:02}", day))?.get(format!("part{}", part))?;
+        match value {
+            Value::String(s) => Some(s.clone()),
+            Value::Integer(i) => Some(i.to_string()),
+            _ => None,
+        }
+    }
+}
+
+fn load_table(path: &Path) -> Result<Table, String> {
+    let text = read_to_string(path).map_err(|e| format!("can't read {}: {}", path.display(), e))?;
+    return text.parse::<Table>().map_err(|e| format!("can't parse {}: {}", path.display(), e));
+}
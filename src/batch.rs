@@ -0,0 +1,231 @@
+// Running a day over a directory of inputs, for `--inputs <dir>` on the day binaries, and the tables the
+// runs are reported in.
+use std::any::Any;
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::answers::Answers;
+use crate::cancel;
+use crate::days::{get_day, Day};
+use crate::input::read_to_string;
+
+
+/// The extension of the file beside an input that holds its answers, `alice.answer` for `alice.txt`. It's a
+/// day's table from the answers file on its own:
+///
+/// ```toml
+/// part1 = "74394"
+/// part2 = "212836"
+/// ```
+pub const ANSWER_EXTENSION: &str = "answer";
+
+
+/// Prints the rows under the header, each column as wide as its widest cell.
+pub fn print_table(header: &[&str], rows: &[Vec<String>]) {
+    let header = header.iter().map(|h| h.to_string()).collect::<Vec<String>>();
+    let widths = (0..header.len())
+        .map(|i| rows.iter().chain([&header]).map(|r| r[i].len()).max().unwrap())
+        .collect::<Vec<usize>>();
+    for row in [&header].into_iter().chain(rows.iter()) {
+        let cells = row.iter().zip(&widths).map(|(c, w)| format!("{:<w$}", c, w = w)).collect::<Vec<String>>();
+        println!("{}", cells.join("  ").trim_end());
+    }
+}
+
+/// The message a panic was raised with.
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        return message.to_string();
+    }
+    if let Some(message) = payload.downcast_ref::<String>() {
+        return message.clone();
+    }
+    return "(no message)".to_string();
+}
+
+
+/// The directory given by `--inputs <dir>` in `args`, None without one.
+pub fn inputs_dir(args: &[String]) -> Result<Option<PathBuf>, String> {
+    return match args.iter().position(|arg| arg == "--inputs") {
+        Some(i) => match args.get(i + 1) {
+            Some(dir) => Ok(Some(PathBuf::from(dir))),
+            None => Err("--inputs expects a directory".to_string()),
+        },
+        None => Ok(None),
+    };
+}
+
+/// The directory a day binary was asked to run over with `--inputs <dir>`, if any.
+pub fn inputs_dir_from_args() -> Result<Option<PathBuf>, String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    return inputs_dir(&args);
+}
+
+/// The inputs in `dir` in name order, leaving out answer files and hidden files.
+pub fn input_files(dir: &Path) -> Result<Vec<PathBuf>, String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("can't read {}: {}", dir.display(), e))?;
+    let mut files = entries
+        .flatten()
+        .map(|entry| entry.path())
+        .filter(|path| path.is_file())
+        .filter(|path| path.extension().is_none_or(|extension| extension != ANSWER_EXTENSION))
+        .filter(|path| !path.file_name().unwrap().to_string_lossy().starts_with('.'))
+        .collect::<Vec<PathBuf>>();
+    files.sort();
+    return Ok(files);
+}
+
+
+/// How one part went on one input.
+pub struct PartResult {
+    // the answer and how long it took, or what went wrong
+    pub result: Result<(String, Duration), String>,
+    // from the input's answer file
+    pub expected: Option<String>,
+}
+
+impl PartResult {
+    /// "ok" or "MISMATCH" against the answer file, "error" when the part failed, empty when there's nothing
+    /// to check against.
+    pub fn check(&self) -> &'static str {
+        return match (&self.result, &self.expected) {
+            (Err(_), _) => "error",
+            (Ok(_), None) => "",
+            (Ok((answer, _)), Some(expected)) if answer.trim_end() == expected.trim_end() => "ok",
+            (Ok(_), Some(_)) => "MISMATCH",
+        };
+    }
+
+    pub fn passed(&self) -> bool {
+        return !matches!(self.check(), "error" | "MISMATCH");
+    }
+}
+
+/// How a day went on one input of the directory.
+pub struct InputResults {
+    pub path: PathBuf,
+    // why the answer file beside it couldn't be read, when there's one
+    pub answers_error: Option<String>,
+    pub parts: Vec<PartResult>,
+}
+
+impl InputResults {
+    pub fn passed(&self) -> bool {
+        return self.answers_error.is_none() && self.parts.iter().all(PartResult::passed);
+    }
+}
+
+// one part on one input, a panic or running out of time only failing that part
+fn solve(day: &Day, part: u32, text: &str, path: &Path) -> Result<(String, Duration), String> {
+    let solver = day.part(part).ok_or("not solved")?;
+    let start = Instant::now();
+    let answer = match panic::catch_unwind(AssertUnwindSafe(|| cancel::run(|| solver(text)))) {
+        Ok(Ok(answer)) => answer.map_err(|e| e.in_file(path).to_string()),
+        Ok(Err(cancelled)) => Err(cancelled.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+    };
+    return answer.map(|answer| (answer, start.elapsed()));
+}
+
+/// Runs both parts of `day` on every input in `dir`, checking them against their answer files.
+pub fn run_inputs(day: &Day, dir: &Path) -> Result<Vec<InputResults>, String> {
+    let files = input_files(dir)?;
+    if files.is_empty() {
+        return Err(format!("no inputs in {}", dir.display()));
+    }
+    let mut results = Vec::new();
+    for path in files {
+        let answers_path = path.with_extension(ANSWER_EXTENSION);
+        let (answers, answers_error) = if answers_path.exists() {
+            match Answers::load_input_answers(&answers_path, day.number) {
+                Ok(answers) => (Some(answers), None),
+                Err(e) => (None, Some(e)),
+            }
+        } else {
+            (None, None)
+        };
+        let text = read_to_string(&path).map_err(|e| e.to_string());
+        let parts = (1..=2)
+            .map(|part| PartResult {
+                result: text.clone().and_then(|text| solve(day, part, &text, &path)),
+                expected: answers.as_ref().and_then(|answers| answers.get(day.number, part)),
+            })
+            .collect();
+        results.push(InputResults { path, answers_error, parts });
+    }
+    return Ok(results);
+}
+
+/// Runs day `number` over every input in `dir` and tabulates it, returning whether every input was solved
+/// and matched its answer file.
+pub fn report(number: u32, dir: &Path) -> bool {
+    let day = get_day(number).unwrap_or_else(|| panic!("day {:02} isn't in the registry", number));
+    // the table reports panics, so the default hook's messages would only interleave with it
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let results = run_inputs(day, dir);
+    panic::set_hook(default_hook);
+    let results = match results {
+        Ok(results) => results,
+        Err(e) => {
+            eprintln!("{}", e);
+            return false;
+        }
+    };
+
+    let mut rows = Vec::new();
+    let mut multiline_answers = Vec::new();
+    let mut failures = Vec::new();
+    for input in &results {
+        let name = input.path.file_name().unwrap().to_string_lossy().to_string();
+        let mut row = vec![name.clone()];
+        if let Some(e) = &input.answers_error {
+            failures.push(format!("{}: {}", name, e));
+        }
+        for (part, part_result) in (1..).zip(&input.parts) {
+            match &part_result.result {
+                Ok((answer, elapsed)) => {
+                    if answer.contains('\n') {
+                        multiline_answers.push((name.clone(), part, answer.clone()));
+                        row.push("(see below)".to_string());
+                    } else {
+                        row.push(answer.clone());
+                    }
+                    if part_result.check() == "MISMATCH" {
+                        failures.push(format!(
+                            "{} part {}: expected\n{}\ngot\n{}",
+                            name, part, part_result.expected.as_ref().unwrap().trim_end(), answer.trim_end()
+                        ));
+                    }
+                    row.push(part_result.check().to_string());
+                    row.push(format!("{:.2?}", elapsed));
+                }
+                Err(e) => {
+                    failures.push(format!("{} part {}: {}", name, part, e));
+                    row.push("".to_string());
+                    row.push(part_result.check().to_string());
+                    row.push("".to_string());
+                }
+            }
+        }
+        rows.push(row);
+    }
+
+    print_table(&["Input", "Part 1", "Check", "Time", "Part 2", "Check", "Time"], &rows);
+    let checked = results.iter().filter(|input| input.parts.iter().any(|part| part.expected.is_some())).count();
+    let passed = results.iter().filter(|input| input.passed()).count();
+    println!(
+        "\nDay {:02} on {} inputs in {}: {} passed, {} checked against .{} files",
+        number, results.len(), dir.display(), passed, checked, ANSWER_EXTENSION
+    );
+    for (name, part, answer) in multiline_answers {
+        println!("\n{} part {}:\n{}", name, part, answer.trim_end());
+    }
+    for failure in &failures {
+        println!("\n{}", failure);
+    }
+    return passed == results.len();
+}
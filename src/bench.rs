@@ -0,0 +1,77 @@
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::ParseError;
+use crate::solution::Solution;
+
+
+/// Summary of the repeated timings of one stage, in nanoseconds.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct Stats {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Stats {
+    pub fn from_durations(durations: &[Duration]) -> Self {
+        let mut nanos = durations.iter().map(|d| d.as_nanos() as u64).collect::<Vec<u64>>();
+        nanos.sort();
+        Stats {
+            min_ns: nanos[0],
+            median_ns: nanos[nanos.len() / 2],
+            mean_ns: nanos.iter().sum::<u64>() / nanos.len() as u64,
+        }
+    }
+}
+
+
+#[derive(Serialize, Deserialize)]
+pub struct DayReport {
+    pub day: u32,
+    pub parse: Stats,
+    pub part1: Stats,
+    pub part2: Stats,
+}
+
+/// A bench run over several days, as written to and read back from JSON.
+#[derive(Serialize, Deserialize)]
+pub struct Report {
+    pub input: String,
+    pub runs: u32,
+    pub days: Vec<DayReport>,
+}
+
+impl Report {
+    pub fn day(&self, day: u32) -> Option<&DayReport> {
+        return self.days.iter().find(|d| d.day == day);
+    }
+}
+
+
+/// Times parse, part 1 and part 2 of a day separately, `runs` times each; the parts run on that run's parse.
+pub fn bench_day<S: Solution>(input: &str, runs: u32) -> Result<(Stats, Stats, Stats), ParseError> {
+    let mut parse_times = Vec::new();
+    let mut part1_times = Vec::new();
+    let mut part2_times = Vec::new();
+    for _ in 0..runs.max(1) {
+        let start = Instant::now();
+        let parsed = black_box(S::parse(input)?);
+        parse_times.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part1(&parsed));
+        part1_times.push(start.elapsed());
+
+        let start = Instant::now();
+        black_box(S::part2(&parsed));
+        part2_times.push(start.elapsed());
+    }
+    return Ok((
+        Stats::from_durations(&parse_times),
+        Stats::from_durations(&part1_times),
+        Stats::from_durations(&part2_times),
+    ));
+}
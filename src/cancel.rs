@@ -0,0 +1,132 @@
+// Stopping the days' longest loops part way, so a run over every day reports how far a slow one got instead of
+// hanging on it.
+use std::cell::RefCell;
+use std::env;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+
+
+/// Stops the runs it's given to at their next check, once cancelled or once its deadline has passed.
+#[derive(Debug, Clone, Default)]
+pub struct Token {
+    // shared by the clones, so another thread can cancel
+    cancelled: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+}
+
+impl Token {
+    /// A token that only stops its runs when cancelled.
+    pub fn new() -> Self {
+        return Token::default();
+    }
+
+    /// A token that also stops its runs once `timeout` has passed from now.
+    pub fn with_timeout(timeout: Duration) -> Self {
+        return Token { cancelled: Arc::default(), deadline: Some(Instant::now() + timeout) };
+    }
+
+    /// Stops every run given this token or one of its clones.
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        return self.stopped().is_some();
+    }
+
+    // Some(whether it was the deadline) once the runs should stop
+    fn stopped(&self) -> Option<bool> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            return Some(false);
+        }
+        return self.deadline.filter(|&deadline| Instant::now() >= deadline).map(|_| true);
+    }
+}
+
+
+/// How far a stopped run had got.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Cancelled {
+    // as opposed to cancelled through the token
+    pub timed_out: bool,
+    pub steps: u64,
+    // what the loop had worked out so far, in its own words
+    pub progress: String,
+}
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let stopped = if self.timed_out { "timed out" } else { "cancelled" };
+        return write!(f, "{} after {} steps, {}", stopped, self.steps, self.progress);
+    }
+}
+
+
+thread_local! {
+    // the token of the run on this thread, None outside of one
+    static CURRENT: RefCell<Option<Token>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` on this thread under `token`, returning where it got to if a `check` in it found the token
+/// stopped. Other panics carry on unwinding.
+pub fn run_with<R>(token: &Token, f: impl FnOnce() -> R) -> Result<R, Cancelled> {
+    let outer = CURRENT.with(|current| current.replace(Some(token.clone())));
+    let result = panic::catch_unwind(AssertUnwindSafe(f));
+    CURRENT.with(|current| *current.borrow_mut() = outer);
+    return match result {
+        Ok(value) => Ok(value),
+        Err(payload) => match payload.downcast::<Cancelled>() {
+            Ok(cancelled) => Err(*cancelled),
+            Err(payload) => panic::resume_unwind(payload),
+        },
+    };
+}
+
+/// Runs `f` with the installed timeout, if any, counting from now.
+pub fn run<R>(f: impl FnOnce() -> R) -> Result<R, Cancelled> {
+    let token = match *TIMEOUT.read().unwrap() {
+        Some(timeout) => Token::with_timeout(timeout),
+        None => Token::new(),
+    };
+    return run_with(&token, f);
+}
+
+/// Called once per step of a long loop: stops the loop if its run's token has been stopped, `steps` being
+/// the steps taken so far and `progress` describing what they came to. Does nothing outside of a run.
+pub fn check(steps: u64, progress: impl FnOnce() -> String) {
+    let stopped = CURRENT.with(|current| current.borrow().as_ref().and_then(Token::stopped));
+    if let Some(timed_out) = stopped {
+        // unwound without a panic message, `run_with` reports it
+        panic::resume_unwind(Box::new(Cancelled { timed_out, steps, progress: progress() }));
+    }
+}
+
+
+// how long each part gets in `run`, None for as long as it takes
+static TIMEOUT: RwLock<Option<Duration>> = RwLock::new(None);
+
+/// The time limit given by `--timeout <seconds>` in `args`, None without one.
+pub fn timeout_from_args(args: &[String]) -> Result<Option<Duration>, String> {
+    return match args.iter().position(|arg| arg == "--timeout") {
+        Some(i) => match args.get(i + 1).map(|x| x.parse::<f64>()) {
+            Some(Ok(seconds)) if seconds >= 0.0 => Ok(Some(Duration::from_secs_f64(seconds))),
+            _ => Err("--timeout expects a number of seconds".to_string()),
+        },
+        None => Ok(None),
+    };
+}
+
+/// Makes `timeout` the limit for every `run` from now on, None lifting it.
+pub fn install(timeout: Option<Duration>) {
+    *TIMEOUT.write().unwrap() = timeout;
+}
+
+/// Installs the time limit a day binary was run with.
+pub fn init_from_args() -> Result<(), String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    install(timeout_from_args(&args)?);
+    return Ok(());
+}
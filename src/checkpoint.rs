@@ -0,0 +1,212 @@
+// Saving the state of the days' longest loops as they go, so a crash or Ctrl-C doesn't lose a long run.
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::RwLock;
+use std::time::{Duration, Instant};
+
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::{info, warn};
+
+
+/// Where checkpoint files go, relative to the crate root, unless `--checkpoint-dir` names another directory.
+pub const CHECKPOINT_DIR: &str = "checkpoints";
+
+/// How often a loop saves its state, unless `--checkpoint-every <seconds>` says otherwise.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(60);
+
+
+/// How checkpointing was asked for on the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Settings {
+    pub dir: PathBuf,
+    pub interval: Duration,
+    // pick up from the saved state instead of starting over
+    pub resume: bool,
+}
+
+impl Settings {
+    /// Reads `--checkpoint`, `--checkpoint-every <seconds>`, `--checkpoint-dir <dir>` and `--resume` from
+    /// `args`; any of them turns checkpointing on, None when there are none.
+    pub fn from_args(args: &[String]) -> Result<Option<Self>, String> {
+        let mut settings = Settings { dir: PathBuf::from(CHECKPOINT_DIR), interval: DEFAULT_INTERVAL, resume: false };
+        let mut on = false;
+        let mut args_iter = args.iter();
+        while let Some(arg) = args_iter.next() {
+            match arg.as_str() {
+                "--checkpoint" => on = true,
+                "--resume" => settings.resume = true,
+                "--checkpoint-every" => {
+                    match args_iter.next().map(|x| x.parse::<f64>()) {
+                        Some(Ok(seconds)) if seconds >= 0.0 => settings.interval = Duration::from_secs_f64(seconds),
+                        _ => return Err("--checkpoint-every expects a number of seconds".to_string()),
+                    }
+                    on = true;
+                }
+                "--checkpoint-dir" => {
+                    match args_iter.next() {
+                        Some(dir) => settings.dir = PathBuf::from(dir),
+                        None => return Err("--checkpoint-dir expects a directory".to_string()),
+                    }
+                    on = true;
+                }
+                _ => {}
+            }
+        }
+        return Ok(if on || settings.resume { Some(settings) } else { None });
+    }
+
+    /// Takes the checkpoint flags out of `args`, leaving the rest for the command.
+    pub fn take_from_args(args: &mut Vec<String>) -> Result<Option<Self>, String> {
+        let settings = Settings::from_args(args)?;
+        let mut i = 0;
+        while i < args.len() {
+            match args[i].as_str() {
+                "--checkpoint" | "--resume" => {
+                    args.remove(i);
+                }
+                "--checkpoint-every" | "--checkpoint-dir" => {
+                    args.drain(i..(i + 2).min(args.len()));
+                }
+                _ => i += 1,
+            }
+        }
+        return Ok(settings);
+    }
+}
+
+
+// None while checkpointing is off
+static SETTINGS: RwLock<Option<Settings>> = RwLock::new(None);
+
+/// Makes `settings` what every `open` reads, None turning checkpointing off.
+pub fn install(settings: Option<Settings>) {
+    *SETTINGS.write().unwrap() = settings;
+}
+
+/// Installs the checkpoint settings a day binary was run with.
+pub fn init_from_args() -> Result<(), String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    install(Settings::from_args(&args)?);
+    return Ok(());
+}
+
+
+// 64-bit FNV-1a
+const FNV_OFFSET_BASIS: u64 = 0xcbf29ce484222325;
+const FNV_PRIME: u64 = 0x100000001b3;
+
+/// Identifies what a loop was started with, so a checkpoint is only resumed by a run of the same thing. It's
+/// FNV-1a over the input and parameters fed to it, so unlike std's hashers it comes out the same from one
+/// build or toolchain to the next and a checkpoint outlives a rebuild.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Fingerprint(u64);
+
+impl Default for Fingerprint {
+    fn default() -> Self {
+        return Fingerprint(FNV_OFFSET_BASIS);
+    }
+}
+
+impl Fingerprint {
+    pub fn new() -> Self {
+        return Fingerprint::default();
+    }
+
+    pub fn bytes(self, bytes: &[u8]) -> Self {
+        let hash = bytes.iter().fold(self.0, |hash, &byte| (hash ^ byte as u64).wrapping_mul(FNV_PRIME));
+        return Fingerprint(hash);
+    }
+
+    pub fn number(self, number: i64) -> Self {
+        return self.bytes(&number.to_le_bytes());
+    }
+
+    /// Led by its length, so "ab" then "c" isn't taken for "a" then "bc".
+    pub fn text(self, text: &str) -> Self {
+        return self.number(text.len() as i64).bytes(text.as_bytes());
+    }
+
+    pub fn finish(self) -> u64 {
+        return self.0;
+    }
+}
+
+// what's on disk, the state under the fingerprint of the run it belongs to
+#[derive(Serialize, Deserialize)]
+struct Saved<T> {
+    fingerprint: u64,
+    state: T,
+}
+
+
+/// One loop's checkpoint file, saved to every interval and left behind when the loop ends, so a later run can
+/// also carry on past it.
+pub struct Checkpoint {
+    path: PathBuf,
+    fingerprint: u64,
+    interval: Duration,
+    resume: bool,
+    last_save: Instant,
+}
+
+/// The checkpoint named `name` for a run with the given fingerprint, None while checkpointing is off.
+pub fn open(name: &str, fingerprint: u64) -> Option<Checkpoint> {
+    let settings = SETTINGS.read().unwrap();
+    let settings = settings.as_ref()?;
+    return Some(Checkpoint {
+        path: settings.dir.join(format!("{}.json", name)),
+        fingerprint,
+        interval: settings.interval,
+        resume: settings.resume,
+        last_save: Instant::now(),
+    });
+}
+
+impl Checkpoint {
+    /// The saved state to carry on from, None unless resuming and there's one from the same run.
+    pub fn resume<T: DeserializeOwned>(&self) -> Option<T> {
+        if !self.resume {
+            return None;
+        }
+        let text = fs::read_to_string(&self.path).ok()?;
+        return match serde_json::from_str::<Saved<T>>(&text) {
+            Ok(saved) if saved.fingerprint == self.fingerprint => {
+                info!("resuming from {}", self.path.display());
+                Some(saved.state)
+            }
+            Ok(_) => {
+                warn!("{} is from a different run, starting over", self.path.display());
+                None
+            }
+            Err(e) => {
+                warn!("can't read {}, starting over: {}", self.path.display(), e);
+                None
+            }
+        };
+    }
+
+    /// Whether an interval has passed since the last save.
+    pub fn due(&self) -> bool {
+        return self.last_save.elapsed() >= self.interval;
+    }
+
+    /// Writes `state` over the previous save. A failed save is reported and the loop carries on without it.
+    pub fn save<T: Serialize>(&mut self, state: &T) {
+        let saved = Saved { fingerprint: self.fingerprint, state };
+        // written aside and renamed over, so an interrupted save leaves the last one whole
+        let partial = self.path.with_extension("json.partial");
+        // the path is always in the checkpoint directory
+        let result = fs::create_dir_all(self.path.parent().unwrap())
+            .and_then(|_| fs::write(&partial, serde_json::to_string(&saved).unwrap()))
+            .and_then(|_| fs::rename(&partial, &self.path));
+        if let Err(e) = result {
+            // the last whole save, if any, is still there to resume from
+            let _ = fs::remove_file(&partial);
+            warn!("can't save {}: {}", self.path.display(), e);
+        }
+        self.last_save = Instant::now();
+    }
+}
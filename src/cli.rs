@@ -0,0 +1,37 @@
+// The flags every day binary takes, read and installed in one go before it solves anything.
+use std::process;
+
+use crate::batch;
+use crate::cancel;
+use crate::checkpoint;
+use crate::image::ImageOptions;
+use crate::log;
+use crate::params;
+use crate::render::{self, Renderer};
+use crate::trace;
+
+
+/// What a day binary was asked to show besides its answers.
+pub struct Setup {
+    /// Where to save the day's picture, if anywhere, and in what colours.
+    pub image_options: ImageOptions,
+    /// How to play the day's animation, headless unless one was asked for.
+    pub renderer: Box<dyn Renderer>,
+}
+
+/// Installs the logging, parameters, time limit, trace and checkpoint settings day `day`'s binary was run
+/// with, and reads its picture and animation flags. Given `--inputs <dir>` it runs the day over the directory
+/// instead and exits, with 0 if every input passed.
+pub fn init(day: u32) -> Result<Setup, String> {
+    log::init_from_args();
+    params::init_from_args(day)?;
+    cancel::init_from_args()?;
+    trace::init_from_args()?;
+    checkpoint::init_from_args()?;
+    if let Some(dir) = batch::inputs_dir_from_args()? {
+        process::exit(if batch::report(day, &dir) { 0 } else { 1 });
+    }
+    let image_options = ImageOptions::from_env_args()?;
+    let renderer = render::renderer_from_args()?;
+    return Ok(Setup { image_options, renderer });
+}
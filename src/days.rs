@@ -0,0 +1,270 @@
+use std::path::{Path, PathBuf};
+
+use crate::{
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15,
+    day16, day17,
+};
+use crate::bench::{bench_day, Stats};
+use crate::error::ParseError;
+use crate::generate::Generator;
+use crate::image::{Image, Palette};
+use crate::input::resolve_input;
+use crate::params::ParamSpec;
+use crate::solution::Solution;
+
+
+// takes the input text, returns the answer
+pub type Solver = fn(&str) -> Result<String, ParseError>;
+
+fn part1<S: Solution>(input: &str) -> Result<String, ParseError> {
+    return Ok(S::part1(&S::parse(input)?).to_string());
+}
+
+fn part2<S: Solution>(input: &str) -> Result<String, ParseError> {
+    return Ok(S::part2(&S::parse(input)?).to_string());
+}
+
+// takes the input text, returns every problem with its shape, see `Solution::lint`
+pub type Linter = fn(&str) -> Vec<ParseError>;
+
+fn lint<S: Solution>(input: &str) -> Vec<ParseError> {
+    return S::lint(input);
+}
+
+// takes the input text and the colours to use, returns a picture of where the day ends up
+pub type Drawer = fn(&str, &Palette) -> Result<Image, ParseError>;
+
+pub struct Picture {
+    // what it shows, after the day's number
+    pub caption: &'static str,
+    pub draw: Drawer,
+}
+
+// takes the input text and a number of runs, returns the parse, part 1 and part 2 timings
+pub type Bencher = fn(&str, u32) -> Result<(Stats, Stats, Stats), ParseError>;
+
+
+pub struct Day {
+    pub number: u32,
+    // directory holding the day's source and inputs, relative to the crate root
+    pub dir: &'static str,
+    pub part1: Solver,
+    pub part2: Solver,
+    pub bench: Bencher,
+    pub lint: Linter,
+    pub generate: Generator,
+    // for the days with something to see, for the report
+    pub picture: Option<Picture>,
+    // what the day reads through `params::Param`, for checking configured values
+    pub params: &'static [ParamSpec],
+}
+
+impl Day {
+    pub fn part(&self, part: u32) -> Option<Solver> {
+        match part {
+            1 => Some(self.part1),
+            2 => Some(self.part2),
+            _ => None,
+        }
+    }
+
+    /// Resolves an input name against the day's directory, see `input::resolve_input`.
+    pub fn input_path(&self, name: &str) -> PathBuf {
+        return resolve_input(Path::new(self.dir), name);
+    }
+}
+
+
+pub static DAYS: &[Day] = &[
+    Day {
+        number: 1,
+        dir: "src/01rs",
+        part1: part1::<day01::Day01>,
+        part2: part2::<day01::Day01>,
+        bench: bench_day::<day01::Day01>,
+        lint: lint::<day01::Day01>,
+        generate: day01::generate,
+        picture: None,
+        params: &[],
+    },
+    Day {
+        number: 2,
+        dir: "src/02rs",
+        part1: part1::<day02::Day02>,
+        part2: part2::<day02::Day02>,
+        bench: bench_day::<day02::Day02>,
+        lint: lint::<day02::Day02>,
+        generate: day02::generate,
+        picture: None,
+        params: &[],
+    },
+    Day {
+        number: 3,
+        dir: "src/03rs",
+        part1: part1::<day03::Day03>,
+        part2: part2::<day03::Day03>,
+        bench: bench_day::<day03::Day03>,
+        lint: lint::<day03::Day03>,
+        generate: day03::generate,
+        picture: None,
+        params: &[],
+    },
+    Day {
+        number: 4,
+        dir: "src/04rs",
+        part1: part1::<day04::Day04>,
+        part2: part2::<day04::Day04>,
+        bench: bench_day::<day04::Day04>,
+        lint: lint::<day04::Day04>,
+        generate: day04::generate,
+        picture: None,
+        params: &[],
+    },
+    Day {
+        number: 5,
+        dir: "src/05rs",
+        part1: part1::<day05::Day05>,
+        part2: part2::<day05::Day05>,
+        bench: bench_day::<day05::Day05>,
+        lint: lint::<day05::Day05>,
+        generate: day05::generate,
+        picture: None,
+        params: &[],
+    },
+    Day {
+        number: 6,
+        dir: "src/06rs",
+        part1: part1::<day06::Day06>,
+        part2: part2::<day06::Day06>,
+        bench: bench_day::<day06::Day06>,
+        lint: lint::<day06::Day06>,
+        generate: day06::generate,
+        picture: None,
+        params: &[],
+    },
+    Day {
+        number: 7,
+        dir: "src/07rs",
+        part1: part1::<day07::Day07>,
+        part2: part2::<day07::Day07>,
+        bench: bench_day::<day07::Day07>,
+        lint: lint::<day07::Day07>,
+        generate: day07::generate,
+        picture: None,
+        params: day07::PARAMS,
+    },
+    Day {
+        number: 8,
+        dir: "src/08rs",
+        part1: part1::<day08::Day08>,
+        part2: part2::<day08::Day08>,
+        bench: bench_day::<day08::Day08>,
+        lint: lint::<day08::Day08>,
+        generate: day08::generate,
+        picture: None,
+        params: &[],
+    },
+    Day {
+        number: 9,
+        dir: "src/09rs",
+        part1: part1::<day09::Day09>,
+        part2: part2::<day09::Day09>,
+        bench: bench_day::<day09::Day09>,
+        lint: lint::<day09::Day09>,
+        generate: day09::generate,
+        picture: None,
+        params: day09::PARAMS,
+    },
+    Day {
+        number: 10,
+        dir: "src/10rs",
+        part1: part1::<day10::Day10>,
+        part2: part2::<day10::Day10>,
+        bench: bench_day::<day10::Day10>,
+        lint: lint::<day10::Day10>,
+        generate: day10::generate,
+        picture: Some(Picture { caption: "the CRT screen", draw: day10::draw }),
+        params: day10::PARAMS,
+    },
+    Day {
+        number: 11,
+        dir: "src/11rs",
+        part1: part1::<day11::Day11>,
+        part2: part2::<day11::Day11>,
+        bench: bench_day::<day11::Day11>,
+        lint: lint::<day11::Day11>,
+        generate: day11::generate,
+        picture: None,
+        params: day11::PARAMS,
+    },
+    Day {
+        number: 12,
+        dir: "src/12rs",
+        part1: part1::<day12::Day12>,
+        part2: part2::<day12::Day12>,
+        bench: bench_day::<day12::Day12>,
+        lint: lint::<day12::Day12>,
+        generate: day12::generate,
+        picture: Some(Picture { caption: "how far the hill climb explored from S", draw: day12::draw }),
+        params: &[],
+    },
+    Day {
+        number: 13,
+        dir: "src/13rs",
+        part1: part1::<day13::Day13>,
+        part2: part2::<day13::Day13>,
+        bench: bench_day::<day13::Day13>,
+        lint: lint::<day13::Day13>,
+        generate: day13::generate,
+        picture: None,
+        params: &[],
+    },
+    Day {
+        number: 14,
+        dir: "src/14rs",
+        part1: part1::<day14::Day14>,
+        part2: part2::<day14::Day14>,
+        bench: bench_day::<day14::Day14>,
+        lint: lint::<day14::Day14>,
+        generate: day14::generate,
+        picture: Some(Picture { caption: "the sand come to rest", draw: day14::draw }),
+        params: &[],
+    },
+    Day {
+        number: 15,
+        dir: "src/15rs",
+        part1: part1::<day15::Day15>,
+        part2: part2::<day15::Day15>,
+        bench: bench_day::<day15::Day15>,
+        lint: lint::<day15::Day15>,
+        generate: day15::generate,
+        picture: None,
+        params: day15::PARAMS,
+    },
+    Day {
+        number: 16,
+        dir: "src/16rs",
+        part1: part1::<day16::Day16>,
+        part2: part2::<day16::Day16>,
+        bench: bench_day::<day16::Day16>,
+        lint: lint::<day16::Day16>,
+        generate: day16::generate,
+        picture: None,
+        params: day16::PARAMS,
+    },
+    Day {
+        number: 17,
+        dir: "src/17rs",
+        part1: part1::<day17::Day17>,
+        part2: part2::<day17::Day17>,
+        bench: bench_day::<day17::Day17>,
+        lint: lint::<day17::Day17>,
+        generate: day17::generate,
+        picture: Some(Picture { caption: "the top of the tower", draw: day17::draw }),
+        params: day17::PARAMS,
+    },
+];
+
+pub fn get_day(number: u32) -> Option<&'static Day> {
+    return DAYS.iter().find(|d| d.number == number);
+}
@@ -0,0 +1,85 @@
+use std::error::Error;
+use std::fmt;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+
+/// Why a day's input couldn't be parsed, and where. Lines and columns count from 1.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// Some text on a line doesn't have the expected shape.
+    Malformed {
+        file: Option<PathBuf>,
+        line: usize,
+        column: usize,
+        text: String,
+        expected: String,
+    },
+    /// The input ended, or a block stopped, before something it needs.
+    Missing {
+        file: Option<PathBuf>,
+        line: usize,
+        expected: String,
+    },
+}
+
+impl ParseError {
+    /// `span` is where the offending text is in `line_text`, in bytes; the column is where it starts.
+    pub fn malformed(line: usize, line_text: &str, span: Range<usize>, expected: &str) -> Self {
+        // a span off the line still reports the line, rather than panicking over the wrong error
+        let column = line_text.get(..span.start).map_or(1, |before| before.chars().count() + 1);
+        let text = line_text.get(span).unwrap_or(line_text);
+        return ParseError::Malformed {
+            file: None,
+            line,
+            column,
+            text: text.to_string(),
+            expected: expected.to_string(),
+        };
+    }
+
+    pub fn missing(line: usize, expected: &str) -> Self {
+        return ParseError::Missing { file: None, line, expected: expected.to_string() };
+    }
+
+    /// The line at fault, or the one the missing thing should have been on.
+    pub fn line(&self) -> usize {
+        return match self {
+            ParseError::Malformed { line, .. } | ParseError::Missing { line, .. } => *line,
+        };
+    }
+
+    /// Records the file the input came from, the parsers only see its text.
+    pub fn in_file(self, path: &Path) -> Self {
+        return match self {
+            ParseError::Malformed { line, column, text, expected, .. } => {
+                ParseError::Malformed { file: Some(path.to_path_buf()), line, column, text, expected }
+            }
+            ParseError::Missing { line, expected, .. } => {
+                ParseError::Missing { file: Some(path.to_path_buf()), line, expected }
+            }
+        };
+    }
+}
+
+fn source_name(file: &Option<PathBuf>) -> String {
+    return match file {
+        Some(path) => path.display().to_string(),
+        None => "<input>".to_string(),
+    };
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        return match self {
+            ParseError::Malformed { file, line, column, text, expected } => {
+                write!(f, "{}:{}:{}: expected {}, found {:?}", source_name(file), line, column, expected, text)
+            }
+            ParseError::Missing { file, line, expected } => {
+                write!(f, "{}:{}: expected {}", source_name(file), line, expected)
+            }
+        };
+    }
+}
+
+impl Error for ParseError {}
@@ -0,0 +1,60 @@
+// Synthetic inputs for stress testing the days beyond their one real input.
+
+
+// takes a random source and a size, whose meaning is up to the day, returns an input its parse accepts
+pub type Generator = fn(&mut Rng, usize) -> String;
+
+/// The size `aoc22 generate` uses when `--size` isn't given.
+pub const DEFAULT_SIZE: usize = 100;
+
+
+/// A small seedable random source (SplitMix64), the same seed gives the same input on every platform.
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        return Rng { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        return z ^ (z >> 31);
+    }
+
+    /// A number in `low..high`, which mustn't be empty.
+    pub fn range(&mut self, low: i64, high: i64) -> i64 {
+        assert!(low < high, "empty range {}..{}", low, high);
+        return low + (self.next_u64() % (high - low) as u64) as i64;
+    }
+
+    /// An index below `n`, which mustn't be 0.
+    pub fn below(&mut self, n: usize) -> usize {
+        return self.range(0, n as i64) as usize;
+    }
+
+    /// True with the given probability.
+    pub fn chance(&mut self, probability: f64) -> bool {
+        let fraction = (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64;
+        return fraction < probability;
+    }
+
+    pub fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.below(items.len())];
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            let j = self.below(i + 1);
+            items.swap(i, j);
+        }
+    }
+}
+
+
+pub const LOWERCASE: &str = "abcdefghijklmnopqrstuvwxyz";
+pub const UPPERCASE: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
@@ -0,0 +1,327 @@
+use std::collections::{HashMap, HashSet};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::render::Renderer;
+
+
+pub type Rgb = [u8; 3];
+
+/// Colours for the days' states: one per grid character, and a gradient for numeric grids.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette {
+    pub chars: HashMap<char, Rgb>,
+    /// For characters the palette doesn't name.
+    pub other: Rgb,
+    /// The colours of the smallest and largest values of a numeric grid.
+    pub low: Rgb,
+    pub high: Rgb,
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        let chars = HashMap::from([
+            ('.', [16, 16, 24]),
+            (' ', [16, 16, 24]),
+            ('#', [200, 200, 200]),
+            ('o', [230, 190, 90]),
+            ('@', [220, 80, 60]),
+            ('+', [220, 80, 60]),
+            ('¯', [120, 120, 120]),
+        ]);
+        return Palette { chars, other: [255, 0, 255], low: [10, 40, 20], high: [170, 240, 140] };
+    }
+}
+
+fn parse_rgb(hex: &str) -> Option<Rgb> {
+    let hex = hex.strip_prefix('#').unwrap_or(hex);
+    if hex.len() != 6 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+    let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+    return Some([channel(0), channel(2), channel(4)]);
+}
+
+impl Palette {
+    /// Overrides colours from a spec like ".=000000,#=ffffff,low=002200,high=aaffaa".
+    pub fn with_spec(mut self, spec: &str) -> Result<Self, String> {
+        for entry in spec.split(',').filter(|entry| !entry.is_empty()) {
+            let (key, value) = entry.split_once('=').ok_or(format!("palette entry {:?} isn't key=rrggbb", entry))?;
+            let rgb = parse_rgb(value).ok_or(format!("palette colour {:?} isn't rrggbb", value))?;
+            let mut key_chars = key.chars();
+            match (key, key_chars.next(), key_chars.next()) {
+                ("low", _, _) => self.low = rgb,
+                ("high", _, _) => self.high = rgb,
+                ("other", _, _) => self.other = rgb,
+                (_, Some(c), None) => {
+                    self.chars.insert(c, rgb);
+                }
+                _ => return Err(format!("palette key {:?} isn't a character, low, high or other", key)),
+            }
+        }
+        return Ok(self);
+    }
+
+    pub fn char_colour(&self, c: char) -> Rgb {
+        return *self.chars.get(&c).unwrap_or(&self.other);
+    }
+
+    // where `value` sits between low and high
+    fn gradient(&self, value: i32, min: i32, max: i32) -> Rgb {
+        let t = if max > min { (value - min) as f64 / (max - min) as f64 } else { 1.0 };
+        let mix = |i: usize| (self.low[i] as f64 + t * (self.high[i] as f64 - self.low[i] as f64)).round() as u8;
+        return [mix(0), mix(1), mix(2)];
+    }
+}
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Image {
+    pub width: usize,
+    pub height: usize,
+    /// Row by row from the top left.
+    pub pixels: Vec<Rgb>,
+}
+
+impl Image {
+    pub fn new(width: usize, height: usize, background: Rgb) -> Self {
+        return Image { width, height, pixels: vec![background; width * height] };
+    }
+
+    /// A pixel per character, shorter rows padded with the palette's '.'.
+    pub fn from_chars(grid: &[Vec<char>], palette: &Palette) -> Self {
+        let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+        let mut image = Image::new(width, grid.len(), palette.char_colour('.'));
+        for (y, row) in grid.iter().enumerate() {
+            for (x, c) in row.iter().enumerate() {
+                image.pixels[y * width + x] = palette.char_colour(*c);
+            }
+        }
+        return image;
+    }
+
+    /// A pixel per character of a text frame, as the renderers draw them.
+    pub fn from_text(text: &str, palette: &Palette) -> Self {
+        let grid = text.lines().map(|line| line.chars().collect()).collect::<Vec<Vec<char>>>();
+        return Image::from_chars(&grid, palette);
+    }
+
+    /// A pixel per value, shaded along the palette's gradient from the smallest value to the largest.
+    pub fn from_values(grid: &[Vec<i32>], palette: &Palette) -> Self {
+        let values = grid.iter().flatten();
+        let min = values.clone().copied().min().unwrap_or(0);
+        let max = values.copied().max().unwrap_or(0);
+        let width = grid.iter().map(|row| row.len()).max().unwrap_or(0);
+        let mut image = Image::new(width, grid.len(), palette.low);
+        for (y, row) in grid.iter().enumerate() {
+            for (x, value) in row.iter().enumerate() {
+                image.pixels[y * width + x] = palette.gradient(*value, min, max);
+            }
+        }
+        return image;
+    }
+
+    /// The points' bounding box, the points drawn as '#' on '.'.
+    pub fn from_points(points: &HashSet<(i32, i32)>, palette: &Palette) -> Self {
+        if points.is_empty() {
+            return Image::new(0, 0, palette.char_colour('.'));
+        }
+        let min_x = points.iter().map(|p| p.0).min().unwrap();
+        let max_x = points.iter().map(|p| p.0).max().unwrap();
+        let min_y = points.iter().map(|p| p.1).min().unwrap();
+        let max_y = points.iter().map(|p| p.1).max().unwrap();
+        let width = (max_x - min_x + 1) as usize;
+        let mut image = Image::new(width, (max_y - min_y + 1) as usize, palette.char_colour('.'));
+        for (x, y) in points {
+            image.pixels[(y - min_y) as usize * width + (x - min_x) as usize] = palette.char_colour('#');
+        }
+        return image;
+    }
+
+    /// Each pixel blown up to a `scale` by `scale` square.
+    pub fn scaled(&self, scale: usize) -> Self {
+        let scale = scale.max(1);
+        let mut image = Image::new(self.width * scale, self.height * scale, [0, 0, 0]);
+        for y in 0..image.height {
+            for x in 0..image.width {
+                image.pixels[y * image.width + x] = self.pixels[(y / scale) * self.width + x / scale];
+            }
+        }
+        return image;
+    }
+
+    /// Binary PPM (P6).
+    pub fn to_ppm(&self) -> Vec<u8> {
+        let mut bytes = format!("P6\n{} {}\n255\n", self.width, self.height).into_bytes();
+        bytes.extend(self.pixels.iter().flatten());
+        return bytes;
+    }
+
+    /// 8-bit RGB PNG, uncompressed.
+    pub fn to_png(&self) -> Vec<u8> {
+        let mut bytes = vec![0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a];
+
+        let mut header = Vec::new();
+        header.extend((self.width as u32).to_be_bytes());
+        header.extend((self.height as u32).to_be_bytes());
+        // bit depth 8, colour type RGB, default compression, filtering and no interlace
+        header.extend([8, 2, 0, 0, 0]);
+        png_chunk(&mut bytes, b"IHDR", &header);
+
+        // each row starts with filter type none
+        let mut raw = Vec::with_capacity(self.height * (self.width * 3 + 1));
+        for row in self.pixels.chunks(self.width.max(1)).take(self.height) {
+            raw.push(0);
+            raw.extend(row.iter().flatten());
+        }
+        png_chunk(&mut bytes, b"IDAT", &zlib_stored(&raw));
+        png_chunk(&mut bytes, b"IEND", &[]);
+        return bytes;
+    }
+
+    /// Writes a PNG, or a PPM when the path ends in .ppm.
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        let bytes = match path.extension().and_then(|e| e.to_str()) {
+            Some("ppm") => self.to_ppm(),
+            _ => self.to_png(),
+        };
+        return fs::write(path, bytes).map_err(|e| format!("can't write {}: {}", path.display(), e));
+    }
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xffffffffu32;
+    for byte in data {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            crc = if crc & 1 == 1 { (crc >> 1) ^ 0xedb88320 } else { crc >> 1 };
+        }
+    }
+    return !crc;
+}
+
+fn png_chunk(bytes: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    bytes.extend((data.len() as u32).to_be_bytes());
+    let start = bytes.len();
+    bytes.extend(kind);
+    bytes.extend(data);
+    let crc = crc32(&bytes[start..]);
+    bytes.extend(crc.to_be_bytes());
+}
+
+// a zlib stream of deflate blocks that are stored as is
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    let mut bytes = vec![0x78, 0x01];
+    let blocks = data.chunks(0xffff).collect::<Vec<&[u8]>>();
+    if blocks.is_empty() {
+        bytes.extend([1, 0, 0, 0xff, 0xff]);
+    }
+    for (i, block) in blocks.iter().enumerate() {
+        bytes.push(if i == blocks.len() - 1 { 1 } else { 0 });
+        bytes.extend((block.len() as u16).to_le_bytes());
+        bytes.extend((!(block.len() as u16)).to_le_bytes());
+        bytes.extend(*block);
+    }
+    let (mut a, mut b) = (1u32, 0u32);
+    for byte in data {
+        a = (a + *byte as u32) % 65521;
+        b = (b + a) % 65521;
+    }
+    bytes.extend(((b << 16) | a).to_be_bytes());
+    return bytes;
+}
+
+
+/// How `--image` and `--frames` images are drawn: `--palette <spec>` and `--scale N`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ImageOptions {
+    pub palette: Palette,
+    pub scale: usize,
+    /// Where `--image` writes the day's final state.
+    pub path: Option<PathBuf>,
+}
+
+impl ImageOptions {
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut options = ImageOptions { palette: Palette::default(), scale: 4, path: None };
+        let mut args_iter = args.iter();
+        while let Some(arg) = args_iter.next() {
+            match arg.as_str() {
+                "--palette" => {
+                    match args_iter.next() {
+                        Some(spec) => options.palette = options.palette.with_spec(spec)?,
+                        None => return Err("--palette expects a spec like #=ffffff,.=000000".to_string()),
+                    }
+                }
+                "--scale" => {
+                    match args_iter.next().map(|x| x.parse::<usize>()) {
+                        Some(Ok(scale)) if scale > 0 => options.scale = scale,
+                        _ => return Err("--scale expects a positive number".to_string()),
+                    }
+                }
+                "--image" => {
+                    match args_iter.next() {
+                        Some(path) => options.path = Some(PathBuf::from(path)),
+                        None => return Err("--image expects a path".to_string()),
+                    }
+                }
+                _ => {}
+            }
+        }
+        return Ok(options);
+    }
+
+    /// `from_args` with the running binary's own arguments.
+    pub fn from_env_args() -> Result<Self, String> {
+        let args: Vec<String> = env::args().skip(1).collect();
+        return ImageOptions::from_args(&args);
+    }
+
+    /// Writes the image scaled to `--image`, if it was given.
+    pub fn save(&self, image: &Image) -> Result<(), String> {
+        return match &self.path {
+            Some(path) => image.scaled(self.scale).save(path),
+            None => Ok(()),
+        };
+    }
+}
+
+
+/// Writes each frame as a numbered image in a directory, frame-00001.png onwards.
+pub struct ImageFrames {
+    dir: PathBuf,
+    extension: &'static str,
+    options: ImageOptions,
+    shown: Box<dyn Renderer>,
+    count: usize,
+    error: Option<String>,
+}
+
+impl ImageFrames {
+    pub fn new(dir: &Path, ppm: bool, options: ImageOptions, shown: Box<dyn Renderer>) -> Self {
+        let extension = if ppm { "ppm" } else { "png" };
+        return ImageFrames { dir: dir.to_path_buf(), extension, options, shown, count: 0, error: None };
+    }
+}
+
+impl Renderer for ImageFrames {
+    fn frame(&mut self, frame: &str) {
+        self.count += 1;
+        if self.error.is_none() {
+            let path = self.dir.join(format!("frame-{:05}.{}", self.count, self.extension));
+            let image = Image::from_text(frame, &self.options.palette).scaled(self.options.scale);
+            let written = fs::create_dir_all(&self.dir).map_err(|e| format!("can't create {}: {}", self.dir.display(), e));
+            self.error = written.and_then(|_| image.save(&path)).err();
+        }
+        self.shown.frame(frame);
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        self.shown.finish()?;
+        return match self.error.take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        };
+    }
+}
@@ -0,0 +1,166 @@
+use std::env;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use itertools::Itertools;
+
+use crate::solution::Solution;
+
+
+/// Reads a whole puzzle input file.
+pub fn read_to_string<P>(filename: P) -> io::Result<String>
+where
+    P: AsRef<Path>,
+{
+    fs::read_to_string(filename)
+}
+
+/// Reads a puzzle input file as lines, blank lines included.
+pub fn read_lines<P>(filename: P) -> io::Result<Vec<String>>
+where
+    P: AsRef<Path>,
+{
+    Ok(lines(&read_to_string(filename)?))
+}
+
+/// Reads a puzzle input file as groups of lines separated by blank lines.
+pub fn read_blocks<P>(filename: P) -> io::Result<Vec<Vec<String>>>
+where
+    P: AsRef<Path>,
+{
+    Ok(blocks(&read_to_string(filename)?))
+}
+
+/// Reads a puzzle input file as a grid of chars, one row per non-blank line.
+pub fn read_char_grid<P>(filename: P) -> io::Result<Vec<Vec<char>>>
+where
+    P: AsRef<Path>,
+{
+    Ok(char_grid(&read_to_string(filename)?))
+}
+
+
+/// Environment variable naming the input when no `--input` flag is given.
+pub const INPUT_ENV_VAR: &str = "AOC_INPUT";
+
+/// Picks the input name from `--input <name>` in `args`, then `AOC_INPUT`, then "in".
+pub fn input_name(args: &[String]) -> String {
+    if let Some(i) = args.iter().position(|arg| arg == "--input") {
+        if let Some(name) = args.get(i + 1) {
+            return name.clone();
+        }
+    }
+    return env::var(INPUT_ENV_VAR).unwrap_or_else(|_| "in".to_string());
+}
+
+/// Resolves an input name against a day's directory: "in" is its `in.txt`, "ex", "ex2"... its
+/// `<name>.in.txt` files, anything else is taken as a path.
+pub fn resolve_input(day_dir: &Path, name: &str) -> PathBuf {
+    for file_name in [format!("{}.in.txt", name), format!("{}.txt", name)] {
+        let named = day_dir.join(file_name);
+        if named.exists() {
+            return named;
+        }
+    }
+    return PathBuf::from(name);
+}
+
+/// Reads the input with the given name, "-" being stdin.
+pub fn read_named_input(day_dir: &Path, name: &str) -> io::Result<String> {
+    if name == "-" {
+        let mut text = String::new();
+        io::stdin().read_to_string(&mut text)?;
+        return Ok(text);
+    }
+    let path = resolve_input(day_dir, name);
+    return fs::read_to_string(&path)
+        .map_err(|e| io::Error::new(e.kind(), format!("can't read {}: {}", path.display(), e)));
+}
+
+/// Reads the input a day binary was asked for on its command line (see `input_name`), resolved
+/// against `day_dir`.
+pub fn read_input(day_dir: &Path) -> io::Result<String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    return read_named_input(day_dir, &input_name(&args));
+}
+
+/// Reads and parses the input a day binary was asked for, the error naming the file and line at fault.
+pub fn parse_input<S: Solution>(day_dir: &Path) -> Result<S::Input, String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let name = input_name(&args);
+    let text = read_named_input(day_dir, &name).map_err(|e| e.to_string())?;
+    let source = if name == "-" { PathBuf::from("<stdin>") } else { resolve_input(day_dir, &name) };
+    return S::parse(&text).map_err(|e| e.in_file(&source).to_string());
+}
+
+
+pub fn lines(text: &str) -> Vec<String> {
+    text.lines().map(String::from).collect_vec()
+}
+
+/// Groups of numbered lines separated by blank lines, see `numbered_lines`.
+pub fn numbered_blocks(text: &str) -> Vec<Vec<(usize, &str)>> {
+    let mut result = Vec::new();
+    let mut block = Vec::new();
+    for (line_number, line) in numbered_lines(text) {
+        if line.is_empty() {
+            if !block.is_empty() {
+                result.push(block);
+                block = Vec::new();
+            }
+        } else {
+            block.push((line_number, line));
+        }
+    }
+    if !block.is_empty() {
+        result.push(block);
+    }
+    return result;
+}
+
+pub fn blocks(text: &str) -> Vec<Vec<String>> {
+    let mut result = Vec::new();
+    let mut block = Vec::new();
+    for line in text.lines() {
+        if line.is_empty() {
+            if !block.is_empty() {
+                result.push(block);
+                block = Vec::new();
+            }
+        } else {
+            block.push(line.to_string());
+        }
+    }
+    if !block.is_empty() {
+        result.push(block);
+    }
+    return result;
+}
+
+/// Lines paired with their 1-based line numbers, for error reporting.
+pub fn numbered_lines(text: &str) -> Vec<(usize, &str)> {
+    text.lines().enumerate().map(|(i, line)| (i + 1, line)).collect_vec()
+}
+
+pub fn char_grid(text: &str) -> Vec<Vec<char>> {
+    text.lines()
+        .filter(|line| !line.is_empty())
+        .map(|line| line.chars().collect_vec())
+        .collect_vec()
+}
+
+
+pub fn parse_int(s: &str) -> Option<i32> {
+    s.trim().parse::<i32>().ok()
+}
+
+/// Parses a coordinate pair, with or without axis labels: "498,4" or "x=5, y=-1" => (5,-1)
+pub fn parse_tuple_int(s: &str) -> Option<(i32, i32)> {
+    let (x, y) = s
+        .split(',')
+        .map(|x| x.trim().trim_start_matches(|c: char| c.is_alphabetic() || c == '='))
+        .map(parse_int)
+        .collect_tuple()?;
+    return Some((x?, y?));
+}
@@ -0,0 +1,53 @@
+pub mod error;
+pub mod log;
+pub mod input;
+pub mod days;
+pub mod solution;
+pub mod answers;
+pub mod batch;
+pub mod bench;
+pub mod cancel;
+pub mod checkpoint;
+pub mod cli;
+pub mod generate;
+pub mod params;
+pub mod render;
+pub mod report;
+pub mod trace;
+pub mod image;
+pub mod watch;
+
+#[path = "01rs/mod.rs"]
+pub mod day01;
+#[path = "02rs/mod.rs"]
+pub mod day02;
+#[path = "03rs/mod.rs"]
+pub mod day03;
+#[path = "04rs/mod.rs"]
+pub mod day04;
+#[path = "05rs/mod.rs"]
+pub mod day05;
+#[path = "06rs/mod.rs"]
+pub mod day06;
+#[path = "07rs/mod.rs"]
+pub mod day07;
+#[path = "08rs/mod.rs"]
+pub mod day08;
+#[path = "09rs/mod.rs"]
+pub mod day09;
+#[path = "10rs/mod.rs"]
+pub mod day10;
+#[path = "11rs/mod.rs"]
+pub mod day11;
+#[path = "12rs/mod.rs"]
+pub mod day12;
+#[path = "13rs/mod.rs"]
+pub mod day13;
+#[path = "14rs/mod.rs"]
+pub mod day14;
+#[path = "15rs/mod.rs"]
+pub mod day15;
+#[path = "16rs/mod.rs"]
+pub mod day16;
+#[path = "17rs/mod.rs"]
+pub mod day17;
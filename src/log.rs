@@ -0,0 +1,170 @@
+use std::env;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::OnceLock;
+
+
+/// Selects which days log, e.g. "day15" or "day11=debug,day17"; days listed without a level use the
+/// -v level, or info without one.
+pub const LOG_ENV_VAR: &str = "AOC_LOG";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    /// Something that went wrong without stopping the day, logged unless -q.
+    Warn = 0,
+    /// Phases of a day's work and what they found, -v.
+    Info = 1,
+    /// Progress through a day's loops, -vv.
+    Debug = 2,
+    /// Every step of the hottest loops, -vvv.
+    Trace = 3,
+}
+
+impl Level {
+    fn from_u8(level: u8) -> Option<Level> {
+        return match level {
+            1 => Some(Level::Info),
+            2 => Some(Level::Debug),
+            3 => Some(Level::Trace),
+            _ => None,
+        };
+    }
+
+    fn parse(name: &str) -> Option<Level> {
+        return match name {
+            "info" => Some(Level::Info),
+            "debug" => Some(Level::Debug),
+            "trace" => Some(Level::Trace),
+            _ => None,
+        };
+    }
+
+    fn name(&self) -> &'static str {
+        return match self {
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+    }
+}
+
+
+// the most verbose level any target logs at, 0 when logging is off, so disabled calls cost one load
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(0);
+// per-target levels from $AOC_LOG, None when every target logs at MAX_LEVEL
+static TARGETS: OnceLock<Option<Vec<(String, Level)>>> = OnceLock::new();
+// -q, leaving out even the warnings
+static QUIET: AtomicBool = AtomicBool::new(false);
+
+
+/// How verbose a -v style flag asks for, None if the argument isn't one. -q is 0, quieter than no flag.
+pub fn verbosity(arg: &str) -> Option<u8> {
+    return match arg {
+        "-q" => Some(0),
+        "-v" => Some(1),
+        "-vv" => Some(2),
+        "-vvv" => Some(3),
+        _ => None,
+    };
+}
+
+/// Turns logging on from the -v flags in `args` and $AOC_LOG, or the warnings off with -q. Only the first call
+/// has an effect.
+pub fn init(args: &[String]) {
+    let verbosity = args.iter().filter_map(|arg| verbosity(arg)).max();
+    let quiet = verbosity == Some(0);
+    let spec = env::var(LOG_ENV_VAR).ok().filter(|spec| !spec.trim().is_empty());
+    if TARGETS.get().is_some() {
+        return;
+    }
+
+    let default_level = verbosity.and_then(Level::from_u8);
+    let targets = spec.map(|spec| {
+        spec.split(',')
+            .map(str::trim)
+            .filter(|entry| !entry.is_empty())
+            .map(|entry| match entry.split_once('=') {
+                Some((target, level)) => {
+                    let level = Level::parse(level.trim()).unwrap_or_else(|| {
+                        eprintln!("{}: unknown level in {:?}, using info", LOG_ENV_VAR, entry);
+                        Level::Info
+                    });
+                    (target.trim().to_string(), level)
+                }
+                None => (entry.to_string(), default_level.unwrap_or(Level::Info)),
+            })
+            .collect::<Vec<(String, Level)>>()
+    });
+
+    let max_level = match &targets {
+        Some(targets) => targets.iter().map(|(_, level)| *level as u8).max().unwrap_or(0),
+        None => default_level.map_or(0, |level| level as u8),
+    };
+    if TARGETS.set(targets).is_ok() {
+        MAX_LEVEL.store(max_level, Ordering::Relaxed);
+        QUIET.store(quiet, Ordering::Relaxed);
+    }
+}
+
+/// `init` with the running binary's own arguments.
+pub fn init_from_args() {
+    let args: Vec<String> = env::args().skip(1).collect();
+    init(&args);
+}
+
+// "aoc22::day11" logs as "day11"
+fn target_name(module_path: &str) -> &str {
+    return module_path.rsplit("::").next().unwrap_or(module_path);
+}
+
+pub fn enabled(level: Level, module_path: &str) -> bool {
+    // warnings don't wait to be asked for, whichever days $AOC_LOG picks
+    if level == Level::Warn {
+        return !QUIET.load(Ordering::Relaxed);
+    }
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) {
+        return false;
+    }
+    return match TARGETS.get() {
+        Some(Some(targets)) => {
+            let target = target_name(module_path);
+            targets.iter().any(|(name, max)| name == target && level <= *max)
+        }
+        _ => true,
+    };
+}
+
+pub fn write(level: Level, module_path: &str, message: std::fmt::Arguments) {
+    eprintln!("[{} {}] {}", target_name(module_path), level.name(), message);
+}
+
+
+/// Logs at `level` for the calling module's day, formatting the message only if that's enabled.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level, module_path!()) {
+            $crate::log::write($level, module_path!(), format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
@@ -1,3 +1,972 @@
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::process::{self, Command, Stdio};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc22::answers::Answers;
+use aoc22::batch::{panic_message, print_table};
+use aoc22::bench::{DayReport, Report, Stats};
+use aoc22::cancel;
+use aoc22::checkpoint;
+use aoc22::days::{get_day, Day, DAYS};
+use aoc22::generate::{Rng, DEFAULT_SIZE};
+use aoc22::image::{Image, Palette};
+use aoc22::input::{read_named_input, INPUT_ENV_VAR};
+use aoc22::log;
+use aoc22::params::{self, ParamConfig};
+use aoc22::report::{self, DayResults, REPORT_PATH};
+use aoc22::trace::{self, Query};
+use aoc22::watch;
+
+
+const USAGE: &str = "\
+usage: aoc22 run <day> [--part 1|2] [--input ex|ex2|in|-|<path>]
+       aoc22 run --all [--input ex|in]
+       aoc22 all [<day>...] [--jobs N] [--input ex|in]
+       aoc22 verify [<day>...] [--answers <path>]
+       aoc22 lint [<day>...] [--input ex|in|-|<path>]
+       aoc22 bench [<day>...] [--input ex|in] [--runs N] [--json <path>] [--baseline <path>]
+       aoc22 generate <day> [--size N] [--seed N] [--output <path>]
+       aoc22 report [<day>...] [--jobs N] [--input ex|in] [--output <path>]
+       aoc22 trace <path> [--steps <n>|<a>..<b>] [--day N] [--run N] [--where <field>=<value>]... [--has <field>]
+                  [--fields <a>,<b>]
+       aoc22 watch <day> [--input ex|in|<path>] [--interval <ms>] [--release] [<day binary flags>...]
+       aoc22 new <day>
+the input defaults to $AOC_INPUT, then in; - reads stdin
+a day binary given --inputs <dir> runs every file in it, checking each against its <name>.answer file if any
+-v, -vv and -vvv log the days' progress to stderr, $AOC_LOG picks which days, e.g. day11=debug,day15; -q hides warnings
+--param [<day>.]<name>=<value> sets a puzzle parameter, e.g. 15.row=10, over params.toml or --params <path>
+--trace <path> writes each step of days 09, 10, 11, 14 and 17 to a JSON lines file, aoc22 trace filters one
+--animate, --fps N and --step play days 10, 14 and 17 on screen, --cast <path> records them for asciinema and
+--frames <dir> saves each frame; a cast keeps the real timing of a run shown on screen, one recorded headless
+is spaced out at --fps, 10 by default
+--timeout <seconds> gives up on each part after that long, saying how far days 15, 16 and 17 got
+--checkpoint saves days 11 and 17's progress to checkpoints/ every minute (--checkpoint-every <seconds>,
+--checkpoint-dir <dir>), --resume carries on from it";
+
+// accepted answers for the real inputs, relative to the crate root
+const ANSWERS_PATH: &str = "answers.toml";
+
+// a new day's module and binary, with NN standing for its zero-padded number and DAY for its number
+const MOD_TEMPLATE: &str = include_str!("template.rs");
+const MAIN_TEMPLATE: &str = "\
+use std::path::Path;
+use std::process;
+
+use aoc22::cli;
+use aoc22::dayNN::DayNN;
+use aoc22::input::parse_input;
+use aoc22::solution::Solution;
+
 fn main() {
-    println!("Hello, world!");
+    cli::init(DAY).unwrap_or_else(|e| {
+        eprintln!(\"{}\", e);
+        process::exit(2);
+    });
+    let day_dir = Path::new(file!()).parent().unwrap();
+
+    let input = parse_input::<DayNN>(day_dir).unwrap_or_else(|e| {
+        eprintln!(\"{}\", e);
+        process::exit(1);
+    });
+
+    println!(\"Part 1 is: {}\", DayNN::part1(&input));
+    println!(\"Total is: {}\", DayNN::part2(&input));
+
+}
+";
+
+
+struct RunOptions {
+    day: Option<u32>,
+    all: bool,
+    part: Option<u32>,
+    input: String,
+}
+
+fn exit_with_usage(message: &str) -> ! {
+    eprintln!("{}\n{}", message, USAGE);
+    process::exit(2);
+}
+
+fn parse_run_options(args: &[String]) -> RunOptions {
+    let input = env::var(INPUT_ENV_VAR).unwrap_or_else(|_| "in".to_string());
+    let mut options = RunOptions { day: None, all: false, part: None, input };
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--all" => options.all = true,
+            "--part" => {
+                match args_iter.next().map(|x| x.parse::<u32>()) {
+                    Some(Ok(part @ 1..=2)) => options.part = Some(part),
+                    _ => exit_with_usage("--part expects 1 or 2"),
+                }
+            }
+            "--input" => {
+                match args_iter.next() {
+                    Some(input) => options.input = input.clone(),
+                    None => exit_with_usage("--input expects ex, in, - or a path"),
+                }
+            }
+            day => {
+                match day.parse::<u32>() {
+                    Ok(number) if options.day.is_none() => options.day = Some(number),
+                    _ => exit_with_usage(&format!("unexpected argument: {}", day)),
+                }
+            }
+        }
+    }
+    if options.all == options.day.is_some() {
+        exit_with_usage("expected a day or --all");
+    }
+    if options.all && options.input == "-" {
+        exit_with_usage("stdin input needs a single day");
+    }
+    return options;
+}
+
+
+struct DayInput {
+    text: String,
+    // where the text came from, for parse errors
+    source: PathBuf,
+}
+
+// reads a day's input once, so both parts can share stdin
+fn load_input(day: &Day, name: &str) -> Result<DayInput, String> {
+    let path = day.input_path(name);
+    if name == "-" {
+        let text = read_named_input(Path::new(day.dir), name).map_err(|e| e.to_string())?;
+        return Ok(DayInput { text, source: PathBuf::from("<stdin>") });
+    }
+    if !path.exists() {
+        return Err(format!("missing input {}", path.display()));
+    }
+    let text = read_named_input(Path::new(day.dir), name).map_err(|e| e.to_string())?;
+    return Ok(DayInput { text, source: path });
+}
+
+// runs one part of a day, None if the part isn't solved
+fn run_part(day: &Day, part: u32, input: &Result<DayInput, String>) -> Option<Result<(String, Duration), String>> {
+    let solver = day.part(part)?;
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => return Some(Err(e.clone())),
+    };
+    let start = Instant::now();
+    let answer = match cancel::run(|| solver(&input.text)) {
+        Ok(answer) => answer.map_err(|e| e.in_file(&input.source).to_string()),
+        Err(cancelled) => Err(cancelled.to_string()),
+    };
+    return Some(answer.map(|answer| (answer, start.elapsed())));
+}
+
+// installs the parameters for `days` running on the named input, exits if they're misconfigured
+fn install_params(config: &ParamConfig, days: &[&Day], input_name: &str) {
+    match config.resolve(days, input_name) {
+        Ok(values) => params::install(values),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    }
+}
+
+fn run_day(day: &Day, parts: &[u32], input_name: &str) {
+    let input = load_input(day, input_name);
+    for &part in parts {
+        match run_part(day, part, &input) {
+            None => println!("Day {:02} part {}: not solved", day.number, part),
+            Some(Err(e)) => println!("Day {:02} part {}: {}", day.number, part, e),
+            Some(Ok((answer, elapsed))) => {
+                if answer.contains('\n') {
+                    println!("Day {:02} part {} ({:.2?}):\n{}", day.number, part, elapsed, answer.trim_end());
+                } else {
+                    println!("Day {:02} part {} ({:.2?}): {}", day.number, part, elapsed, answer);
+                }
+            }
+        }
+    }
+}
+
+fn run_all(parts: &[u32], input_name: &str) {
+    let mut rows = Vec::new();
+    let mut multiline_answers = Vec::new();
+    for day in DAYS.iter() {
+        let input = load_input(day, input_name);
+        let mut row = vec![format!("{:02}", day.number)];
+        let mut total_time = Duration::ZERO;
+        for part in 1..=2 {
+            if !parts.contains(&part) {
+                row.push("".to_string());
+                continue;
+            }
+            let cell = match run_part(day, part, &input) {
+                None => "-".to_string(),
+                Some(Err(e)) => e,
+                Some(Ok((answer, elapsed))) => {
+                    total_time += elapsed;
+                    if answer.contains('\n') {
+                        multiline_answers.push((day.number, part, answer));
+                        "(see below)".to_string()
+                    } else {
+                        answer
+                    }
+                }
+            };
+            row.push(cell);
+        }
+        row.push(format!("{:.2?}", total_time));
+        rows.push(row);
+    }
+
+    print_table(&["Day", "Part 1", "Part 2", "Time"], &rows);
+
+    for (number, part, answer) in multiline_answers {
+        println!("\nDay {:02} part {}:\n{}", number, part, answer.trim_end());
+    }
+}
+
+// draws the day's picture, if it has one, a panic or running out of time only failing the picture
+fn draw_picture(day: &Day, input: &Result<DayInput, String>) -> Option<(&'static str, Result<Image, String>)> {
+    let picture = day.picture.as_ref()?;
+    let input = match input {
+        Ok(input) => input,
+        Err(e) => return Some((picture.caption, Err(e.clone()))),
+    };
+    let drawn = match panic::catch_unwind(AssertUnwindSafe(|| cancel::run(|| (picture.draw)(&input.text, &Palette::default())))) {
+        Ok(Ok(drawn)) => drawn.map_err(|e| e.in_file(&input.source).to_string()),
+        Ok(Err(cancelled)) => Err(cancelled.to_string()),
+        Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+    };
+    return Some((picture.caption, drawn));
+}
+
+// runs both parts of a day, and draws it when asked to, a panic only failing the part it happened in
+fn run_day_isolated(day: &Day, input_name: &str, draw: bool) -> DayResults {
+    let input = load_input(day, input_name);
+    let parts = (1..=2)
+        .map(|part| match panic::catch_unwind(AssertUnwindSafe(|| run_part(day, part, &input))) {
+            Ok(Some(result)) => result,
+            Ok(None) => Err("not solved".to_string()),
+            Err(payload) => Err(format!("panicked: {}", panic_message(payload.as_ref()))),
+        })
+        .collect();
+    let picture = if draw { draw_picture(day, &input) } else { None };
+    return DayResults { number: day.number, parts, picture };
+}
+
+// the days `all` and `report` run, the input they run on and how many at once
+struct AllOptions {
+    days: Vec<&'static Day>,
+    input_name: String,
+    jobs: usize,
+}
+
+fn parse_all_options(args: &[String]) -> AllOptions {
+    let mut input_name = env::var(INPUT_ENV_VAR).unwrap_or_else(|_| "in".to_string());
+    let mut jobs = thread::available_parallelism().map_or(1, |n| n.get());
+    let mut days = Vec::new();
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--input" => {
+                match args_iter.next() {
+                    Some(input) if input != "-" => input_name = input.clone(),
+                    _ => exit_with_usage("--input expects ex, in or a path"),
+                }
+            }
+            "--jobs" => {
+                match args_iter.next().map(|x| x.parse::<usize>()) {
+                    Some(Ok(n)) if n > 0 => jobs = n,
+                    _ => exit_with_usage("--jobs expects a positive number"),
+                }
+            }
+            day => {
+                match day.parse::<u32>().ok().and_then(get_day) {
+                    Some(day) => days.push(day),
+                    None => exit_with_usage(&format!("no such day: {}", day)),
+                }
+            }
+        }
+    }
+    if days.is_empty() {
+        days = DAYS.iter().collect();
+    }
+    return AllOptions { days, input_name, jobs };
+}
+
+// runs days concurrently on a pool of threads, returning their results in day order and how long it took
+fn run_days(options: &AllOptions, draw: bool) -> (Vec<DayResults>, Duration) {
+    let AllOptions { days, input_name, jobs } = options;
+    // the results report panics, so the default hook's messages would only interleave with it
+    let default_hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+    let start = Instant::now();
+    let next_day = AtomicUsize::new(0);
+    let results = Mutex::new(Vec::new());
+    thread::scope(|scope| {
+        for _ in 0..(*jobs).min(days.len()) {
+            scope.spawn(|| loop {
+                let i = next_day.fetch_add(1, Ordering::Relaxed);
+                if i >= days.len() {
+                    break;
+                }
+                let day_results = run_day_isolated(days[i], input_name, draw);
+                results.lock().unwrap().push(day_results);
+            });
+        }
+    });
+    let elapsed = start.elapsed();
+    panic::set_hook(default_hook);
+
+    let mut results = results.into_inner().unwrap();
+    results.sort_by_key(|day_results| day_results.number);
+    return (results, elapsed);
+}
+
+// runs days concurrently on a pool of `jobs` threads, then tabulates them
+fn all(args: &[String], param_config: &ParamConfig) {
+    let options = parse_all_options(args);
+    install_params(param_config, &options.days, &options.input_name);
+    let (results, elapsed) = run_days(&options, false);
+    let mut rows = Vec::new();
+    let mut multiline_answers = Vec::new();
+    let mut errors = Vec::new();
+    let mut total_time = Duration::ZERO;
+    for day_results in &results {
+        let mut row = vec![format!("{:02}", day_results.number)];
+        for (part, result) in (1..).zip(&day_results.parts) {
+            match result {
+                Ok((answer, elapsed)) => {
+                    total_time += *elapsed;
+                    if answer.contains('\n') {
+                        multiline_answers.push((day_results.number, part, answer));
+                        row.push("(see below)".to_string());
+                    } else {
+                        row.push(answer.clone());
+                    }
+                    row.push(format!("{:.2?}", elapsed));
+                }
+                Err(e) => {
+                    errors.push((day_results.number, part, e));
+                    row.push("error".to_string());
+                    row.push("".to_string());
+                }
+            }
+        }
+        rows.push(row);
+    }
+
+    print_table(&["Day", "Part 1", "Time", "Part 2", "Time"], &rows);
+    println!("\n{} days on {} threads in {:.2?}, {:.2?} of solving", results.len(), options.jobs.min(options.days.len()), elapsed, total_time);
+
+    for (number, part, answer) in multiline_answers {
+        println!("\nDay {:02} part {}:\n{}", number, part, answer.trim_end());
+    }
+    if !errors.is_empty() {
+        println!();
+        for (number, part, e) in &errors {
+            println!("Day {:02} part {}: {}", number, part, e);
+        }
+        process::exit(1);
+    }
+}
+
+// runs the days like `all`, then writes their answers, times and pictures to an HTML page
+fn write_report(args: &[String], param_config: &ParamConfig) {
+    let mut args = args.to_vec();
+    let mut output = REPORT_PATH.to_string();
+    if let Some(i) = args.iter().position(|arg| arg == "--output") {
+        match args.get(i + 1) {
+            Some(path) => output = path.clone(),
+            None => exit_with_usage("--output expects a path"),
+        }
+        args.drain(i..i + 2);
+    }
+    let options = parse_all_options(&args);
+    install_params(param_config, &options.days, &options.input_name);
+    let (results, elapsed) = run_days(&options, true);
+
+    fs::write(&output, report::html(&results, &options.input_name, elapsed)).unwrap_or_else(|e| {
+        eprintln!("can't write {}: {}", output, e);
+        process::exit(1);
+    });
+    let failed = results.iter().filter(|day_results| day_results.failed()).map(|day_results| day_results.number);
+    let failed = failed.map(|number| format!("{:02}", number)).collect::<Vec<String>>();
+    if failed.is_empty() {
+        println!("{} days written to {}", results.len(), output);
+    } else {
+        println!("{} days written to {}, with errors on day {}", results.len(), output, failed.join(", "));
+        process::exit(1);
+    }
+}
+
+
+fn run(args: &[String], param_config: &ParamConfig) {
+    let options = parse_run_options(args);
+    let parts = match options.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+    if options.all {
+        install_params(param_config, &DAYS.iter().collect::<Vec<&Day>>(), &options.input);
+        run_all(&parts, &options.input);
+    } else {
+        let number = options.day.unwrap();
+        match get_day(number) {
+            Some(day) => {
+                install_params(param_config, &[day], &options.input);
+                run_day(day, &parts, &options.input);
+            }
+            None => exit_with_usage(&format!("no such day: {}", number)),
+        }
+    }
+}
+
+
+// re-runs days against their real inputs and compares with the recorded answers, exits 1 on any mismatch or error
+fn verify(args: &[String], param_config: &ParamConfig) {
+    let mut answers_path = ANSWERS_PATH.to_string();
+    let mut days = Vec::new();
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--answers" => {
+                match args_iter.next() {
+                    Some(path) => answers_path = path.clone(),
+                    None => exit_with_usage("--answers expects a path"),
+                }
+            }
+            day => {
+                match day.parse::<u32>().ok().and_then(get_day) {
+                    Some(day) => days.push(day),
+                    None => exit_with_usage(&format!("no such day: {}", day)),
+                }
+            }
+        }
+    }
+    if days.is_empty() {
+        days = DAYS.iter().collect();
+    }
+    install_params(param_config, &days, "in");
+    let answers = match Answers::load(Path::new(&answers_path)) {
+        Ok(answers) => answers,
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(2);
+        }
+    };
+
+    let mut rows = Vec::new();
+    let mut failures = Vec::new();
+    let mut unrecorded = 0;
+    for day in days {
+        let input = load_input(day, "in");
+        for part in 1..=2 {
+            let expected = answers.get(day.number, part);
+            let (status, time) = match (run_part(day, part, &input), expected) {
+                (None, _) => ("not solved".to_string(), "".to_string()),
+                (Some(Err(e)), _) => {
+                    failures.push(format!("Day {:02} part {}: {}", day.number, part, e));
+                    ("error".to_string(), "".to_string())
+                }
+                (Some(Ok((answer, elapsed))), None) => {
+                    unrecorded += 1;
+                    failures.push(format!("Day {:02} part {}: no recorded answer, got:\n{}", day.number, part, answer.trim_end()));
+                    ("unrecorded".to_string(), format!("{:.2?}", elapsed))
+                }
+                (Some(Ok((answer, elapsed))), Some(expected)) => {
+                    if answer.trim_end() == expected.trim_end() {
+                        ("ok".to_string(), format!("{:.2?}", elapsed))
+                    } else {
+                        failures.push(format!(
+                            "Day {:02} part {}: expected\n{}\ngot\n{}",
+                            day.number, part, expected.trim_end(), answer.trim_end()
+                        ));
+                        ("MISMATCH".to_string(), format!("{:.2?}", elapsed))
+                    }
+                }
+            };
+            rows.push(vec![format!("{:02}", day.number), part.to_string(), status, time]);
+        }
+    }
+
+    print_table(&["Day", "Part", "Result", "Time"], &rows);
+    for failure in &failures {
+        println!("\n{}", failure);
+    }
+    if failures.len() > unrecorded {
+        process::exit(1);
+    }
+}
+
+
+// checks days' inputs against what their solutions assume about them without solving, exits 1 on any problem
+fn lint(args: &[String]) {
+    let mut input_name = env::var(INPUT_ENV_VAR).unwrap_or_else(|_| "in".to_string());
+    let mut days = Vec::new();
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--input" => {
+                match args_iter.next() {
+                    Some(input) => input_name = input.clone(),
+                    None => exit_with_usage("--input expects ex, in, - or a path"),
+                }
+            }
+            day => {
+                match day.parse::<u32>().ok().and_then(get_day) {
+                    Some(day) => days.push(day),
+                    None => exit_with_usage(&format!("no such day: {}", day)),
+                }
+            }
+        }
+    }
+    if days.is_empty() {
+        days = DAYS.iter().collect();
+    }
+    if days.len() > 1 && input_name == "-" {
+        exit_with_usage("stdin input needs a single day");
+    }
+
+    let mut failed = false;
+    for day in days {
+        let input = match load_input(day, &input_name) {
+            Ok(input) => input,
+            Err(e) => {
+                println!("Day {:02}: {}", day.number, e);
+                failed = true;
+                continue;
+            }
+        };
+        let problems = (day.lint)(&input.text);
+        for problem in &problems {
+            println!("{}", problem.clone().in_file(&input.source));
+        }
+        match problems.len() {
+            0 => println!("Day {:02}: ok", day.number),
+            1 => println!("Day {:02}: 1 problem", day.number),
+            n => println!("Day {:02}: {} problems", day.number, n),
+        }
+        failed |= !problems.is_empty();
+    }
+    if failed {
+        process::exit(1);
+    }
+}
+
+
+// median of a stage, with its change against the baseline's median if there is one
+fn format_stats(stats: &Stats, baseline: Option<&Stats>) -> String {
+    let median = format!("{:.2?}", Duration::from_nanos(stats.median_ns));
+    match baseline {
+        Some(baseline) if baseline.median_ns > 0 => {
+            let change = (stats.median_ns as f64 / baseline.median_ns as f64 - 1.0) * 100.0;
+            return format!("{} ({:+.0}%)", median, change);
+        }
+        _ => return median,
+    }
+}
+
+// times each day's parse and parts over repeated runs, optionally saving the report and comparing it to a saved one
+fn bench(args: &[String], param_config: &ParamConfig) {
+    let mut input_name = env::var(INPUT_ENV_VAR).unwrap_or_else(|_| "in".to_string());
+    let mut runs = 5;
+    let mut json_path = None;
+    let mut baseline_path = None;
+    let mut days = Vec::new();
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--input" => {
+                match args_iter.next() {
+                    Some(input) if input != "-" => input_name = input.clone(),
+                    _ => exit_with_usage("--input expects ex, in or a path"),
+                }
+            }
+            "--runs" => {
+                match args_iter.next().map(|x| x.parse::<u32>()) {
+                    Some(Ok(n)) if n > 0 => runs = n,
+                    _ => exit_with_usage("--runs expects a positive number"),
+                }
+            }
+            "--json" => {
+                match args_iter.next() {
+                    Some(path) => json_path = Some(path.clone()),
+                    None => exit_with_usage("--json expects a path"),
+                }
+            }
+            "--baseline" => {
+                match args_iter.next() {
+                    Some(path) => baseline_path = Some(path.clone()),
+                    None => exit_with_usage("--baseline expects a path"),
+                }
+            }
+            day => {
+                match day.parse::<u32>().ok().and_then(get_day) {
+                    Some(day) => days.push(day),
+                    None => exit_with_usage(&format!("no such day: {}", day)),
+                }
+            }
+        }
+    }
+    if days.is_empty() {
+        days = DAYS.iter().collect();
+    }
+    install_params(param_config, &days, &input_name);
+    let baseline = baseline_path.map(|path| {
+        let report = fs::read_to_string(&path)
+            .map_err(|e| e.to_string())
+            .and_then(|text| serde_json::from_str::<Report>(&text).map_err(|e| e.to_string()));
+        match report {
+            Ok(report) => report,
+            Err(e) => {
+                eprintln!("can't load baseline {}: {}", path, e);
+                process::exit(2);
+            }
+        }
+    });
+
+    let mut report = Report { input: input_name.clone(), runs, days: Vec::new() };
+    let mut rows = Vec::new();
+    for day in days {
+        let input = match load_input(day, &input_name) {
+            Ok(input) => input,
+            Err(e) => {
+                rows.push(vec![format!("{:02}", day.number), e, "".to_string(), "".to_string()]);
+                continue;
+            }
+        };
+        let (parse, part1, part2) = match (day.bench)(&input.text, runs) {
+            Ok(stats) => stats,
+            Err(e) => {
+                rows.push(vec![format!("{:02}", day.number), e.in_file(&input.source).to_string(), "".to_string(), "".to_string()]);
+                continue;
+            }
+        };
+        let day_baseline = baseline.as_ref().and_then(|b| b.day(day.number));
+        rows.push(vec![
+            format!("{:02}", day.number),
+            format_stats(&parse, day_baseline.map(|b| &b.parse)),
+            format_stats(&part1, day_baseline.map(|b| &b.part1)),
+            format_stats(&part2, day_baseline.map(|b| &b.part2)),
+        ]);
+        report.days.push(DayReport { day: day.number, parse, part1, part2 });
+    }
+
+    println!("median of {} runs on {}", runs, input_name);
+    if let Some(baseline) = &baseline {
+        if baseline.input != input_name {
+            println!("warning: the baseline was measured on {}", baseline.input);
+        }
+    }
+    print_table(&["Day", "Parse", "Part 1", "Part 2"], &rows);
+
+    if let Some(path) = json_path {
+        let json = serde_json::to_string_pretty(&report).unwrap();
+        if let Err(e) = fs::write(&path, json + "\n") {
+            eprintln!("can't write {}: {}", path, e);
+            process::exit(1);
+        }
+    }
+}
+
+
+// writes a synthetic input for a day, the same seed and size giving the same input
+fn generate(args: &[String]) {
+    let mut size = DEFAULT_SIZE;
+    let mut seed = 0;
+    let mut output = None;
+    let mut day = None;
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--size" => {
+                match args_iter.next().map(|x| x.parse::<usize>()) {
+                    Some(Ok(n)) => size = n,
+                    _ => exit_with_usage("--size expects a number"),
+                }
+            }
+            "--seed" => {
+                match args_iter.next().map(|x| x.parse::<u64>()) {
+                    Some(Ok(n)) => seed = n,
+                    _ => exit_with_usage("--seed expects a number"),
+                }
+            }
+            "--output" => {
+                match args_iter.next() {
+                    Some(path) => output = Some(path.clone()),
+                    None => exit_with_usage("--output expects a path"),
+                }
+            }
+            number => {
+                match number.parse::<u32>().ok().and_then(get_day) {
+                    Some(found) if day.is_none() => day = Some(found),
+                    _ => exit_with_usage(&format!("unexpected argument: {}", number)),
+                }
+            }
+        }
+    }
+    let day = day.unwrap_or_else(|| exit_with_usage("expected a day"));
+
+    let input = (day.generate)(&mut Rng::new(seed), size);
+    match output {
+        Some(path) => {
+            if let Err(e) = fs::write(&path, input) {
+                eprintln!("can't write {}: {}", path, e);
+                process::exit(1);
+            }
+        }
+        None => print!("{}", input),
+    }
+}
+
+
+// prints the records of a trace file that match the query, one per line
+fn query_trace(args: &[String]) {
+    let (query, rest) = Query::from_args(args).unwrap_or_else(|e| exit_with_usage(&e));
+    let path = match &rest[..] {
+        [path] => path,
+        _ => exit_with_usage("trace expects a trace file"),
+    };
+    let found = trace::read(path).and_then(|file| query.run(file)).unwrap_or_else(|e| {
+        eprintln!("{}: {}", path, e);
+        process::exit(1);
+    });
+    for record in found {
+        println!("{}", record);
+    }
+}
+
+
+// builds and runs a day's binary through cargo, returning what it printed; build errors and logs go straight
+// to stderr
+fn run_day_binary(day: &Day, release: bool, day_args: &[String]) -> Result<String, String> {
+    let cargo = env::var("CARGO").unwrap_or_else(|_| "cargo".to_string());
+    let mut command = Command::new(cargo);
+    command.args(["run", "--quiet", "--bin", &format!("{:02}", day.number)]);
+    if release {
+        command.arg("--release");
+    }
+    let output = command
+        .arg("--")
+        .args(day_args)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("can't run cargo: {}", e))?;
+    if !output.status.success() {
+        return Err(format!("day {:02} failed ({})", day.number, output.status));
+    }
+    return Ok(String::from_utf8_lossy(&output.stdout).into_owned());
+}
+
+// polls a day's directory and input, rebuilding and re-running the day after every change and showing how
+// its answers moved; runs until interrupted
+fn watch_day(args: &[String]) {
+    let mut day = None;
+    let mut input_name = env::var(INPUT_ENV_VAR).unwrap_or_else(|_| "in".to_string());
+    let mut interval = Duration::from_millis(500);
+    let mut release = false;
+    // everything else is for the day binary
+    let mut day_args = Vec::new();
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--input" => {
+                match args_iter.next() {
+                    Some(input) if input != "-" => input_name = input.clone(),
+                    _ => exit_with_usage("--input expects ex, in or a path"),
+                }
+            }
+            "--interval" => {
+                match args_iter.next().map(|x| x.parse::<u64>()) {
+                    Some(Ok(ms)) if ms > 0 => interval = Duration::from_millis(ms),
+                    _ => exit_with_usage("--interval expects a positive number of milliseconds"),
+                }
+            }
+            "--release" => release = true,
+            number if day.is_none() && !number.starts_with('-') => {
+                match number.parse::<u32>().ok().and_then(get_day) {
+                    Some(found) => day = Some(found),
+                    None => exit_with_usage(&format!("no such day: {}", number)),
+                }
+            }
+            other => day_args.push(other.to_string()),
+        }
+    }
+    let day = day.unwrap_or_else(|| exit_with_usage("expected a day"));
+    day_args.extend(["--input".to_string(), input_name.clone()]);
+
+    let watched = vec![PathBuf::from(day.dir), day.input_path(&input_name)];
+    let mut files = watch::snapshot(&watched);
+    let mut reason = format!("watching {} and {}", day.dir, day.input_path(&input_name).display());
+    let mut previous: Option<String> = None;
+    loop {
+        println!("\n== {}, running day {:02} on {}", reason, day.number, input_name);
+        match run_day_binary(day, release, &day_args) {
+            Ok(output) => {
+                match &previous {
+                    None => print!("{}", output),
+                    Some(previous) if *previous == output => println!("answers unchanged:\n{}", output.trim_end()),
+                    Some(previous) => {
+                        for line in watch::diff_lines(previous, &output) {
+                            println!("{}", line);
+                        }
+                    }
+                }
+                previous = Some(output);
+            }
+            Err(e) => println!("{}", e),
+        }
+
+        let changed = loop {
+            thread::sleep(interval);
+            let changed = watch::changed(&files, &watch::snapshot(&watched));
+            if !changed.is_empty() {
+                break changed;
+            }
+        };
+        // editors can write a file in several goes, let them finish
+        thread::sleep(interval);
+        files = watch::snapshot(&watched);
+        let names = changed.iter().map(|path| path.display().to_string()).collect::<Vec<String>>();
+        reason = format!("{} changed", names.join(", "));
+    }
+}
+
+
+// adds `module` to the `use crate::{...}` list of days.rs, keeping it sorted and wrapped
+fn add_day_import(days_source: &str, module: &str) -> Option<String> {
+    let start = days_source.find("use crate::{")?;
+    let end = start + days_source[start..].find("};")? + 2;
+    let mut modules = days_source[start + "use crate::{".len()..end - 2]
+        .split(',')
+        .map(|m| m.trim().to_string())
+        .filter(|m| !m.is_empty())
+        .collect::<Vec<String>>();
+    modules.push(module.to_string());
+    modules.sort();
+
+    let mut import = "use crate::{\n".to_string();
+    let mut line = "   ".to_string();
+    for m in modules {
+        if line.len() + m.len() + 2 > 110 {
+            import += &(line + "\n");
+            line = "   ".to_string();
+        }
+        line += &format!(" {},", m);
+    }
+    import += &(line + "\n};");
+    return Some(days_source[..start].to_string() + &import + &days_source[end..]);
+}
+
+// scaffolds src/NNrs/ from the template and registers it in Cargo.toml, lib.rs and days.rs
+fn new_day(args: &[String]) {
+    let number = match args {
+        [day] => match day.parse::<u32>() {
+            Ok(number @ 1..=25) => number,
+            _ => exit_with_usage(&format!("not a day: {}", day)),
+        },
+        _ => exit_with_usage("new expects a day"),
+    };
+    let nn = format!("{:02}", number);
+    let dir = format!("src/{}rs", nn);
+    if Path::new(&dir).exists() {
+        eprintln!("{} already exists, not overwriting it", dir);
+        process::exit(1);
+    }
+
+    let result = (|| -> Result<(), String> {
+        let read = |path: &str| fs::read_to_string(path).map_err(|e| format!("can't read {}: {}", path, e));
+        let write = |path: &str, text: &str| fs::write(path, text).map_err(|e| format!("can't write {}: {}", path, e));
+        let cargo_toml = read("Cargo.toml")?;
+        let lib = read("src/lib.rs")?;
+        let days = read("src/days.rs")?;
+
+        let bin = format!("[[bin]]\nname = \"{}\"\npath = \"{}/main.rs\"\n", nn, dir);
+        if cargo_toml.contains(&format!("name = \"{}\"", nn)) {
+            return Err(format!("Cargo.toml already has a {} binary", nn));
+        }
+        let cargo_toml = match cargo_toml.find("\n[lints") {
+            Some(i) => format!("{}\n{}{}", &cargo_toml[..i], bin, &cargo_toml[i..]),
+            None => format!("{}\n{}", cargo_toml, bin),
+        };
+
+        let lib = format!("{}#[path = \"{}rs/mod.rs\"]\npub mod day{};\n", lib, nn, nn);
+
+        let entry = format!(
+            "    Day {{\n        number: {},\n        dir: \"{}\",\n        part1: part1::<day{nn}::Day{nn}>,\n        \
+             part2: part2::<day{nn}::Day{nn}>,\n        bench: bench_day::<day{nn}::Day{nn}>,\n        \
+             lint: lint::<day{nn}::Day{nn}>,\n        generate: day{nn}::generate,\n        picture: None,\n        params: &[],\n    }},\n",
+            number, dir, nn = nn
+        );
+        let days = add_day_import(&days, &format!("day{}", nn)).ok_or("can't find the imports in src/days.rs")?;
+        let days_start = days.find("pub static DAYS").ok_or("can't find DAYS in src/days.rs")?;
+        let days_end = days_start + days[days_start..].find("\n];").ok_or("can't find the end of DAYS")? + 1;
+        let days = format!("{}{}{}", &days[..days_end], entry, &days[days_end..]);
+
+        fs::create_dir_all(&dir).map_err(|e| format!("can't create {}: {}", dir, e))?;
+        write(&format!("{}/mod.rs", dir), &MOD_TEMPLATE.replace("NN", &nn))?;
+        write(&format!("{}/main.rs", dir), &MAIN_TEMPLATE.replace("NN", &nn).replace("DAY", &number.to_string()))?;
+        write(&format!("{}/in.txt", dir), "")?;
+        write(&format!("{}/ex.in.txt", dir), "")?;
+        write("Cargo.toml", &cargo_toml)?;
+        write("src/lib.rs", &lib)?;
+        write("src/days.rs", &days)?;
+        return Ok(());
+    })();
+
+    match result {
+        Ok(()) => println!("created {}, run it with: cargo run --bin {} -- --input ex", dir, nn),
+        Err(e) => {
+            eprintln!("{}", e);
+            process::exit(1);
+        }
+    }
+}
+
+
+fn main() {
+    let mut args: Vec<String> = env::args().skip(1).collect();
+    // watch hands its flags on to the day binary as they are
+    if args.first().map(String::as_str) == Some("watch") {
+        watch_day(&args[1..]);
+        return;
+    }
+    log::init(&args);
+    args.retain(|arg| log::verbosity(arg).is_none());
+    let param_config = ParamConfig::take_from_args(&mut args).unwrap_or_else(|e| exit_with_usage(&e));
+    let checkpoints = checkpoint::Settings::take_from_args(&mut args).unwrap_or_else(|e| exit_with_usage(&e));
+    checkpoint::install(checkpoints);
+    if let Some(path) = trace::path_from_args(&args).unwrap_or_else(|e| exit_with_usage(&e)) {
+        trace::open(&path).unwrap_or_else(|e| {
+            eprintln!("{}", e);
+            process::exit(2);
+        });
+        let i = args.iter().position(|arg| arg == "--trace").unwrap();
+        args.drain(i..i + 2);
+    }
+    if let Some(timeout) = cancel::timeout_from_args(&args).unwrap_or_else(|e| exit_with_usage(&e)) {
+        cancel::install(Some(timeout));
+        let i = args.iter().position(|arg| arg == "--timeout").unwrap();
+        args.drain(i..i + 2);
+    }
+    match args.first().map(String::as_str) {
+        Some("run") => run(&args[1..], &param_config),
+        Some("all") => all(&args[1..], &param_config),
+        Some("verify") => verify(&args[1..], &param_config),
+        Some("lint") => lint(&args[1..]),
+        Some("bench") => bench(&args[1..], &param_config),
+        Some("generate") => generate(&args[1..]),
+        Some("trace") => query_trace(&args[1..]),
+        Some("report") => write_report(&args[1..], &param_config),
+        Some("new") => new_day(&args[1..]),
+        _ => exit_with_usage("expected a command"),
+    }
 }
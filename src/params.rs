@@ -0,0 +1,231 @@
+use std::collections::HashMap;
+use std::env;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::RwLock;
+
+use toml::{Table, Value};
+
+use crate::days::{get_day, Day};
+use crate::input::{input_name, read_to_string};
+
+
+/// Per-day puzzle parameters, relative to the crate root. A day's table sets them for every input, a table
+/// nested in it under an input's name only for that input:
+///
+/// ```toml
+/// [15.ex]
+/// row = 10
+/// ```
+pub const PARAMS_PATH: &str = "params.toml";
+
+/// A number a day's puzzle text fixes, like day 15's search row, that variants and examples change.
+pub struct Param<T: 'static> {
+    pub day: u32,
+    pub name: &'static str,
+    pub default: T,
+    // the smallest value the day can work with, for numbers that have one
+    pub min: Option<i64>,
+}
+
+impl<T: FromStr + Clone> Param<T> {
+    pub const fn new(day: u32, name: &'static str, default: T) -> Self {
+        return Param { day, name, default, min: None };
+    }
+
+    /// The name, type and smallest value of the parameter, for checking configured values before any day runs.
+    pub const fn spec(&self) -> ParamSpec {
+        return ParamSpec { name: self.name, valid: valid::<T>, min: self.min };
+    }
+
+    /// The configured value, or the default when there isn't one.
+    pub fn get(&self) -> T {
+        let values = VALUES.read().unwrap();
+        let text = match values.as_ref().and_then(|values| values.get(&(self.day, self.name.to_string()))) {
+            Some(text) => text,
+            None => return self.default.clone(),
+        };
+        // values are checked against the spec when they're installed
+        return text
+            .parse::<T>()
+            .unwrap_or_else(|_| panic!("day {:02} parameter {} can't be {:?}", self.day, self.name, text));
+    }
+}
+
+impl<T: Copy> Param<T> {
+    /// The same parameter, refusing values below `min`.
+    pub const fn at_least(self, min: i64) -> Self {
+        return Param { min: Some(min), ..self };
+    }
+}
+
+fn valid<T: FromStr>(text: &str) -> bool {
+    return text.parse::<T>().is_ok();
+}
+
+/// A parameter as the day registry lists it.
+pub struct ParamSpec {
+    pub name: &'static str,
+    valid: fn(&str) -> bool,
+    min: Option<i64>,
+}
+
+
+// (day, name) to the value's text, None until values are installed
+static VALUES: RwLock<Option<HashMap<(u32, String), String>>> = RwLock::new(None);
+
+/// Makes `values` what every `Param::get` reads.
+pub fn install(values: HashMap<(u32, String), String>) {
+    *VALUES.write().unwrap() = Some(values);
+}
+
+
+/// Where parameters come from: the config file, then `--param` overrides on the command line.
+pub struct ParamConfig {
+    // None for the default file, which doesn't have to exist
+    path: Option<String>,
+    overrides: Vec<String>,
+}
+
+impl ParamConfig {
+    /// Reads `--params <path>` and any number of `--param [<day>.]<name>=<value>` from `args`.
+    pub fn from_args(args: &[String]) -> Result<Self, String> {
+        let mut config = ParamConfig { path: None, overrides: Vec::new() };
+        let mut args_iter = args.iter();
+        while let Some(arg) = args_iter.next() {
+            match arg.as_str() {
+                "--params" => {
+                    match args_iter.next() {
+                        Some(path) => config.path = Some(path.clone()),
+                        None => return Err("--params expects a path".to_string()),
+                    }
+                }
+                "--param" => {
+                    match args_iter.next() {
+                        Some(spec) if spec.contains('=') => config.overrides.push(spec.clone()),
+                        _ => return Err("--param expects [<day>.]<name>=<value>".to_string()),
+                    }
+                }
+                _ => {}
+            }
+        }
+        return Ok(config);
+    }
+
+    /// Takes the parameter flags out of `args`, leaving the rest for the command.
+    pub fn take_from_args(args: &mut Vec<String>) -> Result<Self, String> {
+        let config = ParamConfig::from_args(args)?;
+        let mut i = 0;
+        while i < args.len() {
+            if args[i] == "--params" || args[i] == "--param" {
+                args.drain(i..(i + 2).min(args.len()));
+            } else {
+                i += 1;
+            }
+        }
+        return Ok(config);
+    }
+
+    /// The values for `days` running on the input named `input`. Overrides without a day apply to each of
+    /// `days` that has the parameter.
+    pub fn resolve(&self, days: &[&Day], input: &str) -> Result<HashMap<(u32, String), String>, String> {
+        let mut values = HashMap::new();
+        let path = self.path.as_deref().unwrap_or(PARAMS_PATH);
+        if self.path.is_some() || Path::new(path).exists() {
+            let text = read_to_string(path).map_err(|e| format!("can't read {}: {}", path, e))?;
+            let table = text.parse::<Table>().map_err(|e| format!("can't parse {}: {}", path, e))?;
+            resolve_file(&table, input, &mut values).map_err(|e| format!("{}: {}", path, e))?;
+        }
+
+        for spec in &self.overrides {
+            let (key, value) = spec.split_once('=').unwrap();
+            match key.split_once('.') {
+                Some((day, name)) => {
+                    let day = day.parse::<u32>().ok().and_then(get_day).ok_or_else(|| format!("no such day: {}", day))?;
+                    set(&mut values, day, name, value)?;
+                }
+                None => {
+                    let with_param = days
+                        .iter()
+                        .filter(|day| day.params.iter().any(|p| p.name == key))
+                        .collect::<Vec<_>>();
+                    if with_param.is_empty() {
+                        return Err(format!("no day being run has a parameter {}", key));
+                    }
+                    for day in with_param {
+                        set(&mut values, day, key, value)?;
+                    }
+                }
+            }
+        }
+        return Ok(values);
+    }
+}
+
+// each day's own values, then those for the input on top
+fn resolve_file(table: &Table, input: &str, values: &mut HashMap<(u32, String), String>) -> Result<(), String> {
+    let mut for_input = Vec::new();
+    for (key, day_table) in table {
+        let day = key.parse::<u32>().ok().and_then(get_day).ok_or_else(|| format!("no such day: {}", key))?;
+        let day_table = day_table.as_table().ok_or_else(|| format!("[{}] should be a table", key))?;
+        for (name, value) in day_table {
+            match value {
+                Value::Table(input_table) => {
+                    if name == input {
+                        for_input.push((day, input_table));
+                    } else {
+                        // other inputs' values are still checked
+                        for (name, value) in input_table {
+                            set(&mut HashMap::new(), day, name, &value_text(value))?;
+                        }
+                    }
+                }
+                value => set(values, day, name, &value_text(value))?,
+            }
+        }
+    }
+    for (day, input_table) in for_input {
+        for (name, value) in input_table {
+            set(values, day, name, &value_text(value))?;
+        }
+    }
+    return Ok(());
+}
+
+fn value_text(value: &Value) -> String {
+    return match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    };
+}
+
+// checks a value against the day's parameters before recording it
+fn set(values: &mut HashMap<(u32, String), String>, day: &Day, name: &str, value: &str) -> Result<(), String> {
+    let spec = day.params.iter().find(|p| p.name == name).ok_or_else(|| {
+        let names = day.params.iter().map(|p| p.name).collect::<Vec<&str>>();
+        if names.is_empty() {
+            format!("day {:02} has no parameters", day.number)
+        } else {
+            format!("day {:02} has no parameter {}, only {}", day.number, name, names.join(", "))
+        }
+    })?;
+    if !(spec.valid)(value) {
+        return Err(format!("day {:02} parameter {} can't be {:?}", day.number, name, value));
+    }
+    if let Some(min) = spec.min.filter(|&min| value.parse::<i128>().is_ok_and(|n| n < min as i128)) {
+        return Err(format!("day {:02} parameter {} can't be {:?}, it's at least {}", day.number, name, value, min));
+    }
+    values.insert((day.number, name.to_string()), value.to_string());
+    return Ok(());
+}
+
+
+/// Installs the parameters a day binary was run with, from the config file and its `--param` flags, for
+/// the input it was asked for.
+pub fn init_from_args(day: u32) -> Result<(), String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let day = get_day(day).ok_or_else(|| format!("no such day: {}", day))?;
+    let values = ParamConfig::from_args(&args)?.resolve(&[day], &input_name(&args))?;
+    install(values);
+    return Ok(());
+}
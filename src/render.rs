@@ -0,0 +1,261 @@
+use std::env;
+use std::fs::File;
+use std::path::Path;
+use std::io::{self, BufRead, BufWriter, Seek, SeekFrom, Write};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde_json::json;
+
+use crate::image::{ImageFrames, ImageOptions};
+
+
+/// The frame rate `--animate` plays at when `--fps` isn't given.
+pub const DEFAULT_FPS: u32 = 10;
+
+/// Shows the frames of a day's animation, each a screenful of text.
+pub trait Renderer {
+    /// Whether frames are shown at all, so days can skip drawing them.
+    fn is_active(&self) -> bool {
+        return true;
+    }
+
+    fn frame(&mut self, frame: &str);
+
+    /// Called once the animation is over, to write out anything the renderer kept.
+    fn finish(&mut self) -> Result<(), String> {
+        return Ok(());
+    }
+}
+
+
+/// Drops every frame, the days' solvers run with this at full speed.
+pub struct Headless;
+
+impl Renderer for Headless {
+    fn is_active(&self) -> bool {
+        return false;
+    }
+
+    fn frame(&mut self, _frame: &str) {}
+}
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Pace {
+    /// Frames per second.
+    Fps(u32),
+    /// The next frame waits for Enter.
+    Step,
+}
+
+/// Redraws the terminal for each frame.
+pub struct Terminal {
+    pace: Pace,
+}
+
+impl Terminal {
+    pub fn new(pace: Pace) -> Self {
+        return Terminal { pace };
+    }
+}
+
+impl Renderer for Terminal {
+    fn frame(&mut self, frame: &str) {
+        let mut stdout = io::stdout().lock();
+        // clear screen
+        let _ = write!(stdout, "{esc}c{frame}", esc = 27 as char);
+        if !frame.ends_with('\n') {
+            let _ = writeln!(stdout);
+        }
+        let _ = stdout.flush();
+        drop(stdout);
+
+        match self.pace {
+            Pace::Fps(fps) => thread::sleep(Duration::from_secs(1) / fps.max(1)),
+            Pace::Step => {
+                let mut line = String::new();
+                let _ = io::stdin().lock().read_line(&mut line);
+            }
+        }
+    }
+}
+
+
+/// Keeps the latest frame, for days to save their final state.
+#[derive(Default)]
+pub struct LastFrame {
+    pub frame: Option<String>,
+}
+
+impl Renderer for LastFrame {
+    fn frame(&mut self, frame: &str) {
+        self.frame = Some(frame.to_string());
+    }
+}
+
+
+/// Records frames as an asciicast v2 recording, `asciinema play` replays it. Each frame is written out as it
+/// comes, so a long animation isn't held in memory. Frames also shown on screen are stamped with when they
+/// came, so the recording plays back as the run went. A headless run races through its frames, so `fps` is
+/// only the fallback for it: its frames are given synthetic times that far apart.
+pub struct Cast {
+    path: String,
+    fps: u32,
+    shown: Box<dyn Renderer>,
+    start: Instant,
+    file: BufWriter<File>,
+    timestamp: u64,
+    frames: u64,
+    // the largest frame so far, the terminal size the header is rewritten with
+    width: usize,
+    height: usize,
+    error: Option<String>,
+}
+
+impl Cast {
+    /// Creates the recording at `path` and writes its header.
+    pub fn new(path: &str, fps: u32, shown: Box<dyn Renderer>) -> Result<Self, String> {
+        let file = File::create(path).map_err(|e| format!("can't write {}: {}", path, e))?;
+        let timestamp = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |t| t.as_secs());
+        let mut cast = Cast {
+            path: path.to_string(),
+            fps: fps.max(1),
+            shown,
+            start: Instant::now(),
+            file: BufWriter::new(file),
+            timestamp,
+            frames: 0,
+            width: 0,
+            height: 0,
+            error: None,
+        };
+        let header = cast.header();
+        cast.write(&header);
+        return match cast.error.take() {
+            Some(e) => Err(e),
+            None => Ok(cast),
+        };
+    }
+
+    // the size isn't known until the last frame, so it's padded out for `finish` to write over in place
+    fn header(&self) -> String {
+        return format!(
+            "{{\"version\": 2, \"width\": {:<10}, \"height\": {:<10}, \"timestamp\": {}}}\n",
+            self.width.max(1), self.height + 1, self.timestamp
+        );
+    }
+
+    // keeps the first error for `finish` to report
+    fn write(&mut self, text: &str) {
+        if self.error.is_none() {
+            self.error = self.file.write_all(text.as_bytes()).err().map(|e| format!("can't write {}: {}", self.path, e));
+        }
+    }
+}
+
+impl Renderer for Cast {
+    fn frame(&mut self, frame: &str) {
+        let time = if self.shown.is_active() {
+            self.start.elapsed().as_secs_f64()
+        } else {
+            self.frames as f64 / self.fps as f64
+        };
+        self.frames += 1;
+        let lines = frame.lines().collect::<Vec<&str>>();
+        self.width = self.width.max(lines.iter().map(|line| line.chars().count()).max().unwrap_or(0));
+        self.height = self.height.max(lines.len());
+        // clear screen, the recording is replayed as raw terminal output
+        let data = format!("{esc}[H{esc}[2J{}\r\n", lines.join("\r\n"), esc = 27 as char);
+        self.write(&format!("{}\n", json!([(time * 1e6).round() / 1e6, "o", data])));
+        self.shown.frame(frame);
+    }
+
+    fn finish(&mut self) -> Result<(), String> {
+        self.shown.finish()?;
+        // the header again, now that the size is known
+        let header = self.header();
+        if self.error.is_none() {
+            self.error = rewrite_header(&mut self.file, &header).err().map(|e| format!("can't write {}: {}", self.path, e));
+        }
+        return match self.error.take() {
+            Some(e) => Err(e),
+            None => Ok(()),
+        };
+    }
+}
+
+fn rewrite_header(file: &mut BufWriter<File>, header: &str) -> io::Result<()> {
+    file.seek(SeekFrom::Start(0))?;
+    file.write_all(header.as_bytes())?;
+    return file.flush();
+}
+
+
+
+/// Picks a renderer from `--animate`, `--fps N`, `--step`, `--cast <path>` and `--frames <dir>` (PNG, or PPM
+/// with `--frame-format ppm`), headless when none are given. `--fps` on its own animates; alongside `--cast`
+/// alone it only spaces out the headless recording, which has no real timing to keep.
+pub fn renderer(args: &[String]) -> Result<Box<dyn Renderer>, String> {
+    let mut animate = false;
+    let mut step = false;
+    let mut fps = None;
+    let mut cast_path = None;
+    let mut frames_dir = None;
+    let mut ppm_frames = false;
+    let mut args_iter = args.iter();
+    while let Some(arg) = args_iter.next() {
+        match arg.as_str() {
+            "--animate" => animate = true,
+            "--fps" => {
+                match args_iter.next().map(|x| x.parse::<u32>()) {
+                    Some(Ok(n)) if n > 0 => fps = Some(n),
+                    _ => return Err("--fps expects a positive number".to_string()),
+                }
+            }
+            "--step" => step = true,
+            "--cast" => {
+                match args_iter.next() {
+                    Some(path) => cast_path = Some(path.clone()),
+                    None => return Err("--cast expects a path".to_string()),
+                }
+            }
+            "--frames" => {
+                match args_iter.next() {
+                    Some(dir) => frames_dir = Some(dir.clone()),
+                    None => return Err("--frames expects a directory".to_string()),
+                }
+            }
+            "--frame-format" => {
+                match args_iter.next().map(String::as_str) {
+                    Some("png") => ppm_frames = false,
+                    Some("ppm") => ppm_frames = true,
+                    _ => return Err("--frame-format expects png or ppm".to_string()),
+                }
+            }
+            _ => {}
+        }
+    }
+
+    let shown: Box<dyn Renderer> = if step {
+        Box::new(Terminal::new(Pace::Step))
+    } else if animate || (fps.is_some() && cast_path.is_none() && frames_dir.is_none()) {
+        Box::new(Terminal::new(Pace::Fps(fps.unwrap_or(DEFAULT_FPS))))
+    } else {
+        Box::new(Headless)
+    };
+    let shown: Box<dyn Renderer> = match frames_dir {
+        Some(dir) => Box::new(ImageFrames::new(Path::new(&dir), ppm_frames, ImageOptions::from_args(args)?, shown)),
+        None => shown,
+    };
+    return Ok(match cast_path {
+        Some(path) => Box::new(Cast::new(&path, fps.unwrap_or(DEFAULT_FPS), shown)?),
+        None => shown,
+    });
+}
+
+/// `renderer` with the running binary's own arguments.
+pub fn renderer_from_args() -> Result<Box<dyn Renderer>, String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    return renderer(&args);
+}
@@ -0,0 +1,133 @@
+// The self-contained HTML page `aoc22 report` writes after running the days: their answers, how long each
+// part took, and pictures of the days that have something to see.
+use std::time::Duration;
+
+use crate::image::Image;
+
+
+/// Where `aoc22 report` writes the page, unless `--output` names another file.
+pub const REPORT_PATH: &str = "report.html";
+
+// how wide a picture's longer side is drawn, in CSS pixels, before rounding down to whole pixels per square
+const PICTURE_SIZE: usize = 480;
+
+
+/// What one day of a run came to: for each part its answer and time or what went wrong, and for the days
+/// that draw one, their picture or why it couldn't be drawn.
+pub struct DayResults {
+    pub number: u32,
+    pub parts: Vec<Result<(String, Duration), String>>,
+    pub picture: Option<(&'static str, Result<Image, String>)>,
+}
+
+impl DayResults {
+    pub fn failed(&self) -> bool {
+        return self.parts.iter().any(Result::is_err) || matches!(self.picture, Some((_, Err(_))));
+    }
+}
+
+
+/// The page for a run over `input_name` that took `elapsed`, everything inline so it can be opened offline.
+pub fn html(results: &[DayResults], input_name: &str, elapsed: Duration) -> String {
+    let times = results.iter().flat_map(|day| &day.parts).filter_map(|part| part.as_ref().ok().map(|(_, time)| *time));
+    let slowest = times.clone().max().unwrap_or_default();
+    let solving = times.sum::<Duration>();
+
+    let mut page = String::from(HEAD);
+    page.push_str("<h1>Advent of Code 2022</h1>\n");
+    page.push_str(&format!(
+        "<p>{} days on the {} inputs in {:.2?}, {:.2?} of solving.</p>\n",
+        results.len(), escape(input_name), elapsed, solving
+    ));
+
+    page.push_str("<table>\n<tr><th>Day</th><th>Part 1</th><th>Time</th><th>Part 2</th><th>Time</th></tr>\n");
+    for day in results {
+        page.push_str(&format!("<tr><td>{:02}</td>", day.number));
+        for (part, result) in (1..).zip(&day.parts) {
+            match result {
+                Ok((answer, time)) if answer.contains('\n') => {
+                    page.push_str(&format!("<td><pre>{}</pre></td>{}", escape(answer.trim_end()), time_bar(part, *time, slowest)));
+                }
+                Ok((answer, time)) => page.push_str(&format!("<td>{}</td>{}", escape(answer), time_bar(part, *time, slowest))),
+                Err(e) => page.push_str(&format!("<td class=\"error\" colspan=\"2\">{}</td>", escape(e))),
+            }
+        }
+        page.push_str("</tr>\n");
+    }
+    page.push_str("</table>\n");
+
+    let pictures = results.iter().filter_map(|day| day.picture.as_ref().map(|(caption, picture)| (day.number, caption, picture)));
+    for (number, caption, picture) in pictures {
+        let caption = format!("Day {:02}: {}", number, caption);
+        match picture {
+            Ok(image) => {
+                let scale = (PICTURE_SIZE / image.width.max(image.height).max(1)).max(1);
+                page.push_str(&format!(
+                    "<figure><img src=\"data:image/png;base64,{}\" width=\"{}\" height=\"{}\" alt=\"{}\"><figcaption>{}</figcaption></figure>\n",
+                    base64(&image.to_png()), image.width * scale, image.height * scale, escape(&caption), escape(&caption)
+                ));
+            }
+            Err(e) => page.push_str(&format!("<figure><p class=\"error\">{}</p><figcaption>{}</figcaption></figure>\n", escape(e), escape(&caption))),
+        }
+    }
+    page.push_str("</body>\n</html>\n");
+    return page;
+}
+
+// the part's time, with a bar on a log scale from a microsecond up to the slowest part so the quick days
+// don't all come out empty
+fn time_bar(part: u32, time: Duration, slowest: Duration) -> String {
+    let micros = |duration: Duration| (duration.as_secs_f64() * 1e6).max(1.0);
+    let full = micros(slowest).ln();
+    let width = if full > 0.0 { 100.0 * micros(time).ln() / full } else { 100.0 };
+    return format!(
+        "<td class=\"time\"><div class=\"bar part{}\" style=\"width: {:.1}%\"></div>{:.2?}</td>",
+        part, width.max(1.0), time
+    );
+}
+
+fn escape(text: &str) -> String {
+    return text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;");
+}
+
+// standard base64 with padding, for the data URLs
+fn base64(bytes: &[u8]) -> String {
+    const ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+    let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = ((chunk[0] as u32) << 16) | ((*chunk.get(1).unwrap_or(&0) as u32) << 8) | *chunk.get(2).unwrap_or(&0) as u32;
+        for i in 0..4 {
+            if i <= chunk.len() {
+                text.push(ALPHABET[((n >> (18 - 6 * i)) & 0x3f) as usize] as char);
+            } else {
+                text.push('=');
+            }
+        }
+    }
+    return text;
+}
+
+const HEAD: &str = "\
+<!DOCTYPE html>
+<html lang=\"en\">
+<head>
+<meta charset=\"utf-8\">
+<title>Advent of Code 2022</title>
+<style>
+body { background: #0f0f23; color: #cccccc; font-family: \"Source Code Pro\", monospace; margin: 2em; }
+h1 { color: #00cc00; text-shadow: 0 0 2px #00cc00; }
+table { border-collapse: collapse; }
+th, td { padding: 0.2em 0.8em; text-align: left; vertical-align: top; }
+th { color: #ffff66; }
+pre { margin: 0; line-height: 1; }
+.time { min-width: 12em; }
+.bar { height: 0.5em; margin-bottom: 0.2em; }
+.part1 { background: #9999cc; }
+.part2 { background: #ffff66; }
+.error { color: #ff6666; }
+figure { display: inline-block; margin: 1em 2em 1em 0; vertical-align: top; }
+img { image-rendering: pixelated; border: 1px solid #333340; }
+</style>
+</head>
+<body>
+";
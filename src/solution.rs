@@ -0,0 +1,22 @@
+use std::fmt::Display;
+
+use crate::error::ParseError;
+
+
+/// A day's puzzle: parse the input text once, then answer both parts from it.
+pub trait Solution {
+    type Input;
+    type Part1: Display;
+    type Part2: Display;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Self::Part1;
+    fn part2(input: &Self::Input) -> Self::Part2;
+
+    /// Every way the input breaks what the solution assumes about its shape, in line order, without solving.
+    /// Days that assume more than their parse checks list each violation; the rest have only the parse's
+    /// first error to give.
+    fn lint(input: &str) -> Vec<ParseError> {
+        return Self::parse(input).err().into_iter().collect();
+    }
+}
@@ -1,36 +1,31 @@
-use std::fs::File;
-use std::io::{self, BufRead};
-use std::path::Path;
+use crate::error::ParseError;
+use crate::generate::Rng;
+use crate::input::lines;
+use crate::solution::Solution;
 
 
-fn read_lines<P>(filename: P) -> io::Result<io::Lines<io::BufReader<File>>>
-where
-    P: AsRef<Path>,
-{
-    let file = File::open(filename)?;
-    Ok(io::BufReader::new(file).lines())
+// `size` lines
+pub fn generate(rng: &mut Rng, size: usize) -> String {
+    return (0..size).map(|_| format!("{}\n", rng.range(0, 100))).collect();
 }
 
-fn parse(path: &Path) {
-    if let Ok(lines) = read_lines(path) {
-        
-    } else {
-        panic!();
-    }
-    return;
-}
-
-
-fn main() {
-    let path_buf = Path::new(file!()).parent().unwrap().join("ex.in.txt");
-    // let path_buf = Path::new(file!()).parent().unwrap().join("in.txt");
 
-    assert!(path_buf.as_path().exists());
+pub struct DayNN;
 
-    parse(path_buf.as_path());
+impl Solution for DayNN {
+    type Input = Vec<String>;
+    type Part1 = i32;
+    type Part2 = i32;
 
-    let total = 0;
+    fn parse(input: &str) -> Result<Vec<String>, ParseError> {
+        return Ok(lines(input));
+    }
 
-    println!("Total is: {}", total);
+    fn part1(_lines: &Vec<String>) -> i32 {
+        return 0;
+    }
 
+    fn part2(_lines: &Vec<String>) -> i32 {
+        return 0;
+    }
 }
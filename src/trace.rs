@@ -0,0 +1,233 @@
+// Step-by-step traces of the days' simulations, one JSON object per line, for working out where an answer
+// goes wrong.
+use std::env;
+use std::fs::File;
+use std::io::{BufRead, BufReader, LineWriter, Write};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+
+use serde_json::{Map, Value};
+
+
+// set while a trace file is open, so untraced runs don't build their records
+static ENABLED: AtomicBool = AtomicBool::new(false);
+// written a line at a time, so a run that panics still leaves its steps up to the panic
+static SINK: Mutex<Option<LineWriter<File>>> = Mutex::new(None);
+// runs started so far, each loop that traces is a run
+static RUNS: AtomicU64 = AtomicU64::new(0);
+
+
+/// The trace file named by `--trace <path>` in `args`, None without one.
+pub fn path_from_args(args: &[String]) -> Result<Option<String>, String> {
+    return match args.iter().position(|arg| arg == "--trace") {
+        Some(i) => match args.get(i + 1) {
+            Some(path) => Ok(Some(path.clone())),
+            None => Err("--trace expects a path".to_string()),
+        },
+        None => Ok(None),
+    };
+}
+
+/// Starts writing every traced step to `path`, replacing what was there.
+pub fn open(path: &str) -> Result<(), String> {
+    let file = File::create(path).map_err(|e| format!("can't write {}: {}", path, e))?;
+    *SINK.lock().unwrap() = Some(LineWriter::new(file));
+    ENABLED.store(true, Ordering::Relaxed);
+    return Ok(());
+}
+
+/// Stops tracing and closes the file.
+pub fn close() {
+    ENABLED.store(false, Ordering::Relaxed);
+    if let Some(mut sink) = SINK.lock().unwrap().take() {
+        let _ = sink.flush();
+    }
+}
+
+/// Opens the trace file a day binary was asked for with `--trace <path>`, if any.
+pub fn init_from_args() -> Result<(), String> {
+    let args: Vec<String> = env::args().skip(1).collect();
+    if let Some(path) = path_from_args(&args)? {
+        open(&path)?;
+    }
+    return Ok(());
+}
+
+fn write(record: &Value) {
+    if let Some(sink) = SINK.lock().unwrap().as_mut() {
+        let _ = writeln!(sink, "{}", record);
+    }
+}
+
+
+/// One traced run of a day's loop, numbering its steps from 1.
+pub struct Tracer {
+    day: u32,
+    run: u64,
+    step: u64,
+}
+
+/// Starts a run for `day`, recording what it was started with as step 0; None while nothing is traced, so
+/// days only build their records when they're wanted.
+pub fn start(day: u32, setup: Value) -> Option<Tracer> {
+    if !ENABLED.load(Ordering::Relaxed) {
+        return None;
+    }
+    let tracer = Tracer { day, run: RUNS.fetch_add(1, Ordering::Relaxed) + 1, step: 0 };
+    tracer.write(setup);
+    return Some(tracer);
+}
+
+impl Tracer {
+    /// Records the next step, `fields` being a JSON object.
+    pub fn step(&mut self, fields: Value) {
+        self.step += 1;
+        self.write(fields);
+    }
+
+    // the fields after the day, run and step
+    fn write(&self, fields: Value) {
+        let mut record = Map::new();
+        record.insert("day".to_string(), Value::from(self.day));
+        record.insert("run".to_string(), Value::from(self.run));
+        record.insert("step".to_string(), Value::from(self.step));
+        if let Value::Object(fields) = fields {
+            record.extend(fields);
+        }
+        write(&Value::Object(record));
+    }
+}
+
+
+/// Which records of a trace to show, and which of their fields.
+#[derive(Debug, Default)]
+pub struct Query {
+    // inclusive bounds
+    first_step: Option<u64>,
+    last_step: Option<u64>,
+    // (dotted field path, value) pairs that all have to match
+    equals: Vec<(String, String)>,
+    // fields each record has to have
+    has: Vec<String>,
+    // fields to keep, all of them when empty
+    fields: Vec<String>,
+}
+
+impl Query {
+    /// Reads `--steps <n>|<a>..<b>|<a>..=<b>`, `--day <n>`, `--run <n>`, `--where <field>=<value>`,
+    /// `--has <field>` and `--fields <a>,<b>` from `args`; anything else is returned for the caller.
+    pub fn from_args(args: &[String]) -> Result<(Self, Vec<String>), String> {
+        let mut query = Query::default();
+        let mut rest = Vec::new();
+        let mut args_iter = args.iter();
+        while let Some(arg) = args_iter.next() {
+            let mut value = |expected: &str| args_iter.next().cloned().ok_or_else(|| format!("{} expects {}", arg, expected));
+            match arg.as_str() {
+                "--steps" => {
+                    let steps = value("a step or a range of steps like 10..20")?;
+                    (query.first_step, query.last_step) = parse_steps(&steps)
+                        .ok_or_else(|| format!("--steps expects a step or a range of steps like 10..20, not {}", steps))?;
+                }
+                "--day" | "--run" => {
+                    let number = value("a number")?;
+                    number.parse::<u64>().map_err(|_| format!("{} expects a number, not {}", arg, number))?;
+                    query.equals.push((arg.trim_start_matches('-').to_string(), number));
+                }
+                "--where" => {
+                    let condition = value("<field>=<value>")?;
+                    let (field, wanted) = condition.split_once('=').ok_or("--where expects <field>=<value>")?;
+                    query.equals.push((field.to_string(), wanted.to_string()));
+                }
+                "--has" => query.has.push(value("a field")?),
+                "--fields" => query.fields.extend(value("a list of fields")?.split(',').map(String::from)),
+                _ => rest.push(arg.clone()),
+            }
+        }
+        return Ok((query, rest));
+    }
+
+    pub fn matches(&self, record: &Value) -> bool {
+        let step = record.get("step").and_then(Value::as_u64).unwrap_or(0);
+        if self.first_step.is_some_and(|first| step < first) || self.last_step.is_some_and(|last| step > last) {
+            return false;
+        }
+        if !self.has.iter().all(|field| lookup(record, field).is_some()) {
+            return false;
+        }
+        return self.equals.iter().all(|(field, wanted)| lookup(record, field).is_some_and(|value| text(value) == *wanted));
+    }
+
+    /// The record cut down to the asked for fields, under their dotted names.
+    pub fn select(&self, record: Value) -> Value {
+        if self.fields.is_empty() {
+            return record;
+        }
+        let mut selected = Map::new();
+        for field in &self.fields {
+            if let Some(value) = lookup(&record, field) {
+                selected.insert(field.clone(), value.clone());
+            }
+        }
+        return Value::Object(selected);
+    }
+
+    /// The matching records of a trace, each as `select` leaves it; a line that isn't JSON is an error.
+    pub fn run(&self, trace: impl BufRead) -> Result<Vec<Value>, String> {
+        let mut found = Vec::new();
+        for (i, line) in trace.lines().enumerate() {
+            let line = line.map_err(|e| e.to_string())?;
+            if line.trim().is_empty() {
+                continue;
+            }
+            let record = serde_json::from_str::<Value>(&line).map_err(|e| format!("line {}: {}", i + 1, e))?;
+            if self.matches(&record) {
+                found.push(self.select(record));
+            }
+        }
+        return Ok(found);
+    }
+}
+
+// "12", "10..20", "10..=20", "10.." or "..20"
+fn parse_steps(steps: &str) -> Option<(Option<u64>, Option<u64>)> {
+    let bound = |text: &str| if text.is_empty() { Some(None) } else { text.parse::<u64>().ok().map(Some) };
+    if let Some((first, last)) = steps.split_once("..=") {
+        return Some((bound(first)?, Some(last.parse::<u64>().ok()?)));
+    }
+    if let Some((first, last)) = steps.split_once("..") {
+        let last = match bound(last)? {
+            Some(0) => return None,
+            last => last.map(|last| last - 1),
+        };
+        return Some((bound(first)?, last));
+    }
+    let step = steps.parse::<u64>().ok()?;
+    return Some((Some(step), Some(step)));
+}
+
+// a field by its dotted path, array elements by index: "knots.0"
+fn lookup<'a>(record: &'a Value, path: &str) -> Option<&'a Value> {
+    let mut value = record;
+    for part in path.split('.') {
+        value = match value {
+            Value::Object(fields) => fields.get(part)?,
+            Value::Array(items) => items.get(part.parse::<usize>().ok()?)?,
+            _ => return None,
+        };
+    }
+    return Some(value);
+}
+
+// strings compare without their quotes, everything else as JSON
+fn text(value: &Value) -> String {
+    return match value {
+        Value::String(text) => text.clone(),
+        value => value.to_string(),
+    };
+}
+
+
+/// Reads a trace file for `Query::run`.
+pub fn read(path: &str) -> Result<BufReader<File>, String> {
+    return File::open(path).map(BufReader::new).map_err(|e| format!("can't read {}: {}", path, e));
+}
@@ -0,0 +1,79 @@
+// Polling for `aoc22 watch`, which re-runs a day whenever its files change.
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+
+/// The modification time and length of each file, enough to notice a save.
+pub type Snapshot = HashMap<PathBuf, (SystemTime, u64)>;
+
+/// The files at or under each of `paths`, missing ones left out.
+pub fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut files = Snapshot::new();
+    for path in paths {
+        add_files(path, &mut files);
+    }
+    return files;
+}
+
+fn add_files(path: &Path, files: &mut Snapshot) {
+    let metadata = match fs::metadata(path) {
+        Ok(metadata) => metadata,
+        Err(_) => return,
+    };
+    if metadata.is_dir() {
+        if let Ok(entries) = fs::read_dir(path) {
+            for entry in entries.flatten() {
+                add_files(&entry.path(), files);
+            }
+        }
+    } else {
+        let modified = metadata.modified().unwrap_or(SystemTime::UNIX_EPOCH);
+        files.insert(path.to_path_buf(), (modified, metadata.len()));
+    }
+}
+
+/// Files added, removed or modified between two snapshots, in path order.
+pub fn changed(before: &Snapshot, after: &Snapshot) -> Vec<PathBuf> {
+    let mut paths = after
+        .iter()
+        .filter(|(path, stamp)| before.get(*path) != Some(stamp))
+        .map(|(path, _)| path.clone())
+        .chain(before.keys().filter(|path| !after.contains_key(*path)).cloned())
+        .collect::<Vec<PathBuf>>();
+    paths.sort();
+    return paths;
+}
+
+
+/// A line diff from `old` to `new`, each line marked "  " when kept, "- " when removed or "+ " when added.
+pub fn diff_lines(old: &str, new: &str) -> Vec<String> {
+    let old = old.lines().collect::<Vec<&str>>();
+    let new = new.lines().collect::<Vec<&str>>();
+
+    // longest common subsequence of the lines from each position on
+    let mut common = vec![vec![0; new.len() + 1]; old.len() + 1];
+    for i in (0..old.len()).rev() {
+        for j in (0..new.len()).rev() {
+            common[i][j] = if old[i] == new[j] { common[i + 1][j + 1] + 1 } else { common[i + 1][j].max(common[i][j + 1]) };
+        }
+    }
+
+    let mut diff = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < old.len() || j < new.len() {
+        if i < old.len() && j < new.len() && old[i] == new[j] {
+            diff.push(format!("  {}", old[i]));
+            i += 1;
+            j += 1;
+        } else if i < old.len() && (j == new.len() || common[i + 1][j] >= common[i][j + 1]) {
+            diff.push(format!("- {}", old[i]));
+            i += 1;
+        } else {
+            diff.push(format!("+ {}", new[j]));
+            j += 1;
+        }
+    }
+    return diff;
+}
@@ -0,0 +1,22 @@
+// The checked-in answers file parses and records both parts of every day with a real input.
+use std::fs;
+use std::path::Path;
+
+use aoc22::answers::Answers;
+use aoc22::days::DAYS;
+
+
+#[test]
+fn every_day_has_recorded_answers() {
+    let answers = Answers::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("answers.toml")).unwrap();
+    for day in DAYS.iter() {
+        let input = Path::new(env!("CARGO_MANIFEST_DIR")).join(day.dir).join("in.txt");
+        if fs::metadata(&input).map(|m| m.len() == 0).unwrap_or(true) {
+            // freshly scaffolded, nothing to answer yet
+            continue;
+        }
+        for part in 1..=2 {
+            assert!(answers.get(day.number, part).is_some(), "day {} part {} has no answer", day.number, part);
+        }
+    }
+}
@@ -0,0 +1,102 @@
+// `--inputs <dir>` runs a day over every input in a directory, checking each against its answer file.
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use aoc22::batch::{input_files, inputs_dir, run_inputs, InputResults};
+use aoc22::days::{get_day, Day};
+
+
+fn inputs(test: &str, files: &[(&str, &str)]) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc22-inputs-{}-{}", std::process::id(), test));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    for (name, text) in files {
+        fs::write(dir.join(name), text).unwrap();
+    }
+    return dir;
+}
+
+fn day_input(day: &str, name: &str) -> String {
+    return fs::read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/{}rs/{}", day, name))).unwrap();
+}
+
+// each input's file name with its parts' checks
+fn checks(results: &[InputResults]) -> Vec<(String, Vec<&'static str>)> {
+    return results
+        .iter()
+        .map(|input| {
+            let name = input.path.file_name().unwrap().to_string_lossy().to_string();
+            (name, input.parts.iter().map(|part| part.check()).collect())
+        })
+        .collect();
+}
+
+
+#[test]
+fn answers_are_checked_where_recorded() {
+    let dir = inputs("day01", &[
+        ("alice.txt", &day_input("01", "in.txt")),
+        ("alice.answer", "part1 = \"74394\"\npart2 = 212836\n"),
+        ("bob.txt", &day_input("01", "ex.in.txt")),
+        ("bob.answer", "part1 = 24001\n"),
+        ("carol.txt", &day_input("01", "ex.in.txt")),
+        ("dave.txt", "lots\n"),
+        ("erin.txt", &day_input("01", "ex.in.txt")),
+        ("erin.answer", "part1 = \n"),
+    ]);
+    let results = run_inputs(get_day(1).unwrap(), &dir).unwrap();
+    assert_eq!(checks(&results), vec![
+        ("alice.txt".to_string(), vec!["ok", "ok"]),
+        ("bob.txt".to_string(), vec!["MISMATCH", ""]),
+        ("carol.txt".to_string(), vec!["", ""]),
+        ("dave.txt".to_string(), vec!["error", "error"]),
+        ("erin.txt".to_string(), vec!["", ""]),
+    ]);
+    assert_eq!(results.iter().map(InputResults::passed).collect::<Vec<bool>>(), vec![true, false, true, false, false]);
+    assert!(results[3].parts[0].result.as_ref().unwrap_err().contains("dave.txt:1:1"));
+    assert!(results[4].answers_error.as_ref().unwrap().contains("erin.answer"));
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn multiline_answers_and_panics() {
+    let crt = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+    let dir = inputs("day10", &[("example", &day_input("10", "ex.in.txt")), ("example.answer", &format!("part2 = '''\n{}'''\n", crt))]);
+    assert_eq!(checks(&run_inputs(get_day(10).unwrap(), &dir).unwrap()), vec![("example".to_string(), vec!["", "ok"])]);
+    fs::remove_dir_all(&dir).unwrap();
+
+    // too few sensors to surround the distress beacon, which is an answer of its own
+    let dir = inputs("day15", &[("one-sensor.txt", "Sensor at x=0, y=0: closest beacon is at x=1, y=0\n")]);
+    let day15 = get_day(15).unwrap();
+    let results = run_inputs(day15, &dir).unwrap();
+    assert_eq!(checks(&results), vec![("one-sensor.txt".to_string(), vec!["", ""])]);
+    assert_eq!(results[0].parts[1].result.as_ref().unwrap().0, "no distress beacon");
+
+    // a part that panics only fails that part
+    let panicking = Day { part2: |_| panic!("no distress beacon"), picture: None, ..*day15 };
+    let results = run_inputs(&panicking, &dir).unwrap();
+    assert_eq!(checks(&results), vec![("one-sensor.txt".to_string(), vec!["", "error"])]);
+    assert_eq!(results[0].parts[1].result.as_ref().unwrap_err(), "panicked: no distress beacon");
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn which_files_are_inputs() {
+    let dir = inputs("files", &[("b.txt", ""), ("a", ""), ("a.answer", ""), (".notes", "")]);
+    fs::create_dir(dir.join("more")).unwrap();
+    assert_eq!(input_files(&dir).unwrap(), vec![dir.join("a"), dir.join("b.txt")]);
+    fs::remove_dir_all(&dir).unwrap();
+    assert!(run_inputs(get_day(1).unwrap(), &dir).is_err());
+
+    let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    assert_eq!(inputs_dir(&args(&["--input", "ex", "--inputs", "team/"])), Ok(Some(PathBuf::from("team/"))));
+    assert!(inputs_dir(&args(&["--inputs"])).is_err());
+}
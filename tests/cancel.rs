@@ -0,0 +1,89 @@
+// A stopped run reports how far it got instead of an answer, and a run that isn't stopped is unaffected.
+use std::env;
+use std::panic;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc22::cancel::{self, Token};
+use aoc22::day15::{self, Day15};
+use aoc22::day16::{self, Day16};
+use aoc22::day17::{get_rock_tower_height, get_rocks, Day17};
+use aoc22::input::read_to_string;
+use aoc22::render::{Headless, Renderer};
+use aoc22::solution::Solution;
+
+
+fn example<S: Solution>(day: &str) -> S::Input {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/{}rs/ex.in.txt", day));
+    return S::parse(&read_to_string(&path).unwrap()).unwrap();
+}
+
+
+#[test]
+fn each_loop_reports_its_progress_when_timed_out() {
+    let expired = Token::with_timeout(Duration::ZERO);
+
+    let sensors = example::<Day15>("15");
+    let stopped = cancel::run_with(&expired, || day15::get_tuning_frequency(&sensors, 20, 4000000)).unwrap_err();
+    assert!(stopped.timed_out);
+    assert_eq!(stopped.to_string(), "timed out after 0 steps, 0 of 14 sensors' boundaries found");
+
+    let valves = example::<Day16>("16");
+    let stopped = cancel::run_with(&expired, || day16::find_best_path_pressure_sum(&valves, 26)).unwrap_err();
+    assert_eq!(stopped.to_string(), "timed out after 0 steps, 0 sets of valves opened, the best releasing 0");
+
+    let jets = example::<Day17>("17");
+    let stopped = cancel::run_with(&expired, || get_rock_tower_height(&get_rocks(), &jets, 2022, 7, &mut Headless));
+    assert_eq!(stopped.unwrap_err().to_string(), "timed out after 0 steps, 0 of 2022 rocks dropped, the tower 0 high");
+}
+
+// cancels its token part way through the rocks, as a Ctrl-C handler or a watchdog would
+struct CancelAfter {
+    frames_left: usize,
+    token: Token,
+}
+
+impl Renderer for CancelAfter {
+    fn frame(&mut self, _frame: &str) {
+        if self.frames_left == 0 {
+            self.token.cancel();
+        }
+        self.frames_left = self.frames_left.saturating_sub(1);
+    }
+}
+
+
+#[test]
+fn cancelling_a_clone_stops_the_run() {
+    let token = Token::new();
+    let sensors = example::<Day15>("15");
+    assert_eq!(cancel::run_with(&token, || day15::get_tuning_frequency(&sensors, 20, 4000000)), Ok(Some(56000011)));
+
+    let jets = example::<Day17>("17");
+    let mut renderer = CancelAfter { frames_left: 500, token: token.clone() };
+    let stopped = cancel::run_with(&token, || get_rock_tower_height(&get_rocks(), &jets, 2022, 7, &mut renderer)).unwrap_err();
+    assert!(!stopped.timed_out);
+    assert!(stopped.steps > 0 && stopped.steps < 2022, "{}", stopped);
+    assert!(stopped.to_string().starts_with(&format!("cancelled after {} steps, {} of 2022", stopped.steps, stopped.steps)));
+}
+
+#[test]
+fn other_panics_and_unchecked_runs_pass_through() {
+    let token = Token::with_timeout(Duration::ZERO);
+    let panicked = panic::catch_unwind(|| cancel::run_with(&token, || panic!("not a timeout")));
+    assert_eq!(*panicked.unwrap_err().downcast::<&str>().unwrap(), "not a timeout");
+
+    // a check outside of a run never stops
+    cancel::check(1, || unreachable!());
+    assert_eq!(cancel::run_with(&token, || 42), Ok(42));
+}
+
+#[test]
+fn timeouts_from_args() {
+    let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    assert_eq!(cancel::timeout_from_args(&args(&["16", "--timeout", "1.5"])), Ok(Some(Duration::from_millis(1500))));
+    assert_eq!(cancel::timeout_from_args(&args(&["16"])), Ok(None));
+    for bad in [&["--timeout"][..], &["--timeout", "-1"], &["--timeout", "soon"]] {
+        assert!(cancel::timeout_from_args(&args(bad)).is_err(), "{:?}", bad);
+    }
+}
@@ -0,0 +1,121 @@
+// A run interrupted part way and resumed from its checkpoint gives the answer an uninterrupted run does.
+use std::env;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::Duration;
+
+use aoc22::checkpoint::{self, Fingerprint, Settings};
+use aoc22::day11::{self, Day11};
+use aoc22::day17::{get_rock_tower_height, get_rocks, Day17};
+use aoc22::input::read_to_string;
+use aoc22::render::{Headless, Renderer};
+use aoc22::solution::Solution;
+
+
+// the settings are global, so the tests take turns
+static SETTINGS: Mutex<()> = Mutex::new(());
+
+fn checkpoint_dir(test: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!("aoc22-checkpoints-{}-{}", std::process::id(), test));
+    let _ = fs::remove_dir_all(&dir);
+    return dir;
+}
+
+fn install(dir: &Path, resume: bool) {
+    checkpoint::install(Some(Settings { dir: dir.to_path_buf(), interval: Duration::ZERO, resume }));
+}
+
+fn example<S: Solution>(day: &str) -> S::Input {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/{}rs/ex.in.txt", day));
+    return S::parse(&read_to_string(&path).unwrap()).unwrap();
+}
+
+
+// stands in for Ctrl-C part way through the rocks
+struct Interrupt {
+    frames_left: usize,
+}
+
+impl Renderer for Interrupt {
+    fn frame(&mut self, _frame: &str) {
+        if self.frames_left == 0 {
+            panic!("interrupted");
+        }
+        self.frames_left -= 1;
+    }
+}
+
+
+#[test]
+fn day17_resumes_where_it_was_interrupted() {
+    let _turn = SETTINGS.lock().unwrap_or_else(|e| e.into_inner());
+    let jets = example::<Day17>("17");
+    checkpoint::install(None);
+    let uninterrupted = get_rock_tower_height(&get_rocks(), &jets, 2022, 7, &mut Headless);
+
+    let dir = checkpoint_dir("day17");
+    install(&dir, false);
+    let interrupted = panic::catch_unwind(AssertUnwindSafe(|| {
+        get_rock_tower_height(&get_rocks(), &jets, 2022, 7, &mut Interrupt { frames_left: 500 })
+    }));
+    assert!(interrupted.is_err());
+    assert!(dir.join("day17-2022-rocks.json").exists());
+
+    install(&dir, true);
+    let resumed = get_rock_tower_height(&get_rocks(), &jets, 2022, 7, &mut Headless);
+    checkpoint::install(None);
+    assert_eq!(resumed, uninterrupted);
+}
+
+#[test]
+fn day11_carries_on_past_a_shorter_run() {
+    let _turn = SETTINGS.lock().unwrap_or_else(|e| e.into_inner());
+    let monkeys = example::<Day11>("11");
+    checkpoint::install(None);
+    let uninterrupted = day11::run_rounds(monkeys.clone(), 600, false);
+
+    let dir = checkpoint_dir("day11");
+    install(&dir, false);
+    day11::run_rounds(monkeys.clone(), 250, false);
+    install(&dir, true);
+    let resumed = day11::run_rounds(monkeys.clone(), 600, false);
+    assert_eq!(resumed, uninterrupted);
+
+    // a checkpoint from other monkeys is left alone
+    let mut input = read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join("src/11rs/ex.in.txt")).unwrap();
+    input = input.replacen("Starting items: 79, 98", "Starting items: 79, 97", 1);
+    let other_monkeys = Day11::parse(&input).unwrap();
+    let resumed = day11::run_rounds(other_monkeys.clone(), 600, false);
+    checkpoint::install(None);
+    assert_eq!(resumed, day11::run_rounds(other_monkeys, 600, false));
+}
+
+#[test]
+fn fingerprints_are_fnv_1a() {
+    // the published 64-bit FNV-1a values, which a checkpoint written by another build has to match
+    assert_eq!(Fingerprint::new().finish(), 0xcbf29ce484222325);
+    assert_eq!(Fingerprint::new().bytes(b"a").finish(), 0xaf63dc4c8601ec8c);
+    assert_eq!(Fingerprint::new().bytes(b"foobar").finish(), 0x85944171f73967e8);
+    assert_ne!(Fingerprint::new().text("ab").text("c"), Fingerprint::new().text("a").text("bc"));
+}
+
+#[test]
+fn saves_leave_no_partial_file_behind() {
+    let _turn = SETTINGS.lock().unwrap_or_else(|e| e.into_inner());
+    let dir = checkpoint_dir("partial");
+    install(&dir, false);
+    let mut saved = checkpoint::open("saved", 1).unwrap();
+    saved.save(&[1, 2, 3]);
+    assert!(dir.join("saved.json").exists());
+    assert!(!dir.join("saved.json.partial").exists());
+
+    // a directory in the way of the rename fails the save
+    fs::create_dir_all(dir.join("blocked.json")).unwrap();
+    let mut blocked = checkpoint::open("blocked", 1).unwrap();
+    blocked.save(&[1, 2, 3]);
+    checkpoint::install(None);
+    assert!(dir.join("blocked.json").is_dir());
+    assert!(!dir.join("blocked.json.partial").exists());
+}
@@ -0,0 +1,158 @@
+// Example answers published in each day's puzzle text, run against the days' `ex*.in.txt` files.
+use std::path::Path;
+
+use aoc22::input::read_to_string;
+use aoc22::solution::Solution;
+use aoc22::{
+    day01, day02, day03, day04, day05, day06, day07, day08, day09, day10, day11, day12, day13, day14, day15,
+    day16, day17,
+};
+
+
+fn example<S: Solution>(day: &str, name: &str) -> S::Input {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/{}rs/{}.in.txt", day, name));
+    return S::parse(&read_to_string(&path).unwrap()).unwrap();
+}
+
+fn assert_answers<S: Solution>(day: &str, name: &str, part1: &str, part2: &str) {
+    let input = example::<S>(day, name);
+    assert_eq!(S::part1(&input).to_string(), part1, "day {} {} part 1", day, name);
+    assert_eq!(S::part2(&input).to_string(), part2, "day {} {} part 2", day, name);
+}
+
+
+#[test]
+fn day01_example() {
+    assert_answers::<day01::Day01>("01", "ex", "24000", "45000");
+}
+
+#[test]
+fn day02_example() {
+    assert_answers::<day02::Day02>("02", "ex", "15", "12");
+}
+
+#[test]
+fn day03_example() {
+    assert_answers::<day03::Day03>("03", "ex", "157", "70");
+}
+
+#[test]
+fn day04_example() {
+    assert_answers::<day04::Day04>("04", "ex", "2", "4");
+}
+
+#[test]
+fn day05_example() {
+    assert_answers::<day05::Day05>("05", "ex", "CMZ", "MCD");
+}
+
+#[test]
+fn day06_example() {
+    assert_answers::<day06::Day06>("06", "ex", "7", "19");
+}
+
+#[test]
+fn day07_example() {
+    assert_answers::<day07::Day07>("07", "ex", "95437", "24933642");
+}
+
+#[test]
+fn day07_when_no_directory_frees_enough() {
+    // deleting / empties the whole disk, so an update needing more than all of it fits nowhere
+    let sizes = example::<day07::Day07>("07", "ex");
+    assert_eq!(day07::get_size_to_free(&sizes, 70000000, 70000000), Some(48381165u64.into()));
+    assert_eq!(day07::get_size_to_free(&sizes, 70000000, 70000001), None);
+    assert_eq!(day07::SizeToFree(None).to_string(), "no directory frees enough space");
+}
+
+#[test]
+fn day08_example() {
+    assert_answers::<day08::Day08>("08", "ex", "21", "8");
+}
+
+#[test]
+fn day08_without_inner_trees() {
+    // as small as `aoc22 generate 8 --size 2` makes it, every tree on the edge
+    let grid = day08::Day08::parse("30\n25\n").unwrap();
+    assert_eq!(day08::Day08::part1(&grid), 4);
+    assert_eq!(day08::Day08::part2(&grid), 0);
+}
+
+#[test]
+fn day09_example() {
+    assert_answers::<day09::Day09>("09", "ex", "13", "1");
+}
+
+#[test]
+fn day09_larger_example() {
+    assert_answers::<day09::Day09>("09", "ex2", "88", "36");
+}
+
+#[test]
+fn day10_example() {
+    let screen = "\
+##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....
+";
+    assert_answers::<day10::Day10>("10", "ex", "13140", screen);
+}
+
+#[test]
+fn day11_example() {
+    assert_answers::<day11::Day11>("11", "ex", "10605", "2713310158");
+}
+
+#[test]
+fn day12_example() {
+    assert_answers::<day12::Day12>("12", "ex", "31", "29");
+}
+
+#[test]
+fn day12_unreachable_example() {
+    // E is as high as z and none of its neighbours are above f, so there's no path to it
+    let grid = example::<day12::Day12>("12", "ex2");
+    assert_eq!(day12::Day12::part1(&grid), day12::Steps(None));
+    assert_eq!(day12::Day12::part2(&grid), day12::Steps(None));
+    assert_answers::<day12::Day12>("12", "ex2", "E can't be reached", "E can't be reached");
+}
+
+#[test]
+fn day12_starting_from_s_in_part2() {
+    // S is the only square at elevation a, so the hike from it is also the shortest from any a
+    let grid = day12::Day12::parse("SbcdefghijklmnopqrstuvwxyE\n").unwrap();
+    assert_eq!(day12::Day12::part1(&grid), day12::Steps(Some(25)));
+    assert_eq!(day12::Day12::part2(&grid), day12::Steps(Some(25)));
+}
+
+#[test]
+fn day13_example() {
+    assert_answers::<day13::Day13>("13", "ex", "13", "140");
+}
+
+#[test]
+fn day14_example() {
+    assert_answers::<day14::Day14>("14", "ex", "24", "93");
+}
+
+#[test]
+fn day15_example() {
+    // the example asks about row 10 rather than the real input's row 2000000, and searches up to 20 rather
+    // than 4000000
+    let input = example::<day15::Day15>("15", "ex");
+    assert_eq!(day15::get_no_beacon_count(&input, 10), 26);
+    assert_eq!(day15::get_tuning_frequency(&input, 20, day15::TUNING_MULTIPLIER.default), Some(56000011));
+}
+
+#[test]
+fn day16_example() {
+    assert_answers::<day16::Day16>("16", "ex", "1651", "1707");
+}
+
+#[test]
+fn day17_example() {
+    assert_answers::<day17::Day17>("17", "ex", "3068", "1514285714288");
+}
@@ -0,0 +1,27 @@
+// Every day's generated inputs parse and solve part 1, and a seed always gives the same input.
+use aoc22::days::DAYS;
+use aoc22::generate::Rng;
+
+
+#[test]
+fn generated_inputs_parse() {
+    for day in DAYS {
+        for size in [1, 20] {
+            for seed in 0..3 {
+                let input = (day.generate)(&mut Rng::new(seed), size);
+                if let Err(e) = (day.part1)(&input) {
+                    panic!("day {:02} size {} seed {}: {}\n{}", day.number, size, seed, e, input);
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn seeds_are_deterministic() {
+    for day in DAYS {
+        let first = (day.generate)(&mut Rng::new(42), 10);
+        assert_eq!(first, (day.generate)(&mut Rng::new(42), 10), "day {:02}", day.number);
+        assert_ne!(first, (day.generate)(&mut Rng::new(43), 10), "day {:02}", day.number);
+    }
+}
@@ -0,0 +1,42 @@
+// Days' grids and point sets turned into pixels.
+use std::collections::HashSet;
+
+use aoc22::image::{Image, Palette};
+
+
+#[test]
+fn chars_use_the_palette() {
+    let palette = Palette::default().with_spec("#=ff0000,.=000000").unwrap();
+    let image = Image::from_text("#.\n.", &palette);
+    assert_eq!((image.width, image.height), (2, 2));
+    assert_eq!(image.pixels, vec![[255, 0, 0], [0, 0, 0], [0, 0, 0], [0, 0, 0]]);
+    assert!(Palette::default().with_spec("#=red").is_err());
+}
+
+#[test]
+fn values_shade_from_low_to_high() {
+    let palette = Palette::default().with_spec("low=000000,high=ffffff").unwrap();
+    let image = Image::from_values(&[vec![0, 5, 10]], &palette);
+    assert_eq!(image.pixels, vec![[0, 0, 0], [128, 128, 128], [255, 255, 255]]);
+}
+
+#[test]
+fn points_fill_their_bounding_box() {
+    let palette = Palette::default();
+    let image = Image::from_points(&HashSet::from([(-1, 2), (1, 3)]), &palette).scaled(2);
+    assert_eq!((image.width, image.height), (6, 4));
+    assert_eq!(image.pixels[0], palette.char_colour('#'));
+    assert_eq!(image.pixels[2], palette.char_colour('.'));
+}
+
+#[test]
+fn encodes_ppm_and_png() {
+    let image = Image::from_values(&[vec![1, 2], vec![3, 4]], &Palette::default());
+    let ppm = image.to_ppm();
+    assert!(ppm.starts_with(b"P6\n2 2\n255\n"));
+    assert_eq!(ppm.len(), 11 + 2 * 2 * 3);
+
+    let png = image.to_png();
+    assert!(png.starts_with(&[0x89, b'P', b'N', b'G', 0x0d, 0x0a, 0x1a, 0x0a]));
+    assert!(png.ends_with(&[0, 0, 0, 0, b'I', b'E', b'N', b'D', 0xae, 0x42, 0x60, 0x82]));
+}
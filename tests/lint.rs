@@ -0,0 +1,101 @@
+// `aoc22 lint` reports every way an input breaks a day's assumptions, each at its line, and nothing for the
+// inputs the days are known to solve.
+use aoc22::days::DAYS;
+use aoc22::error::ParseError;
+use aoc22::generate::Rng;
+use aoc22::input::read_to_string;
+use aoc22::solution::Solution;
+use aoc22::{day03, day05, day13, day14, day16};
+
+
+// (line, expected) of each problem, in the order they're reported
+fn problems(found: Vec<ParseError>) -> Vec<(usize, String)> {
+    return found
+        .into_iter()
+        .map(|problem| match problem {
+            ParseError::Malformed { line, expected, .. } | ParseError::Missing { line, expected, .. } => (line, expected),
+        })
+        .collect();
+}
+
+fn lines(found: Vec<ParseError>) -> Vec<usize> {
+    return problems(found).into_iter().map(|(line, _)| line).collect();
+}
+
+
+#[test]
+fn the_days_own_and_generated_inputs_are_clean() {
+    for day in DAYS {
+        for name in ["ex", "in"] {
+            let path = day.input_path(name);
+            let input = read_to_string(&path).unwrap();
+            assert_eq!((day.lint)(&input), vec![], "{}", path.display());
+        }
+        for seed in 0..10 {
+            let input = (day.generate)(&mut Rng::new(seed), 10);
+            assert_eq!((day.lint)(&input), vec![], "day {:02} seed {}:\n{}", day.number, seed, input);
+        }
+    }
+}
+
+#[test]
+fn day03_groups_of_three() {
+    let input = "vJrwpWtwJgWrhcsFMMfFFhFp\n\njqHRNqRjqzjGDLGLrsFMfFZSrLrFZsSL\nPmmdzqPrVvPwwTWBwg\nabcab\n";
+    let found = problems(day03::Day03::lint(input));
+    assert_eq!(found.iter().map(|(line, _)| *line).collect::<Vec<usize>>(), vec![2, 3, 5, 6], "{:?}", found);
+    assert!(found[0].1.contains("blank"));
+    assert!(found[1].1.contains("badge"));
+    assert!(found[2].1.contains("even number"));
+    assert!(found[3].1.contains("1 more rucksacks"));
+}
+
+#[test]
+fn day05_two_digit_stacks_and_short_rows() {
+    let mut drawing = (1..=10).map(|n| format!("[{}]", (b'A' + n as u8) as char)).collect::<Vec<String>>().join(" ");
+    drawing = format!("[A]\n{}\n", drawing);
+    let numbers = (1..=10).map(|n| format!(" {} ", n)).collect::<Vec<String>>().join(" ");
+    let input = format!("{}{}\n\nmove 3 from 10 to 1\nmove 1 from 11 to 1\n", drawing, numbers);
+    let found = problems(day05::Day05::lint(&input));
+    assert_eq!(found.iter().map(|(line, _)| *line).collect::<Vec<usize>>(), vec![1, 3, 5, 6, 7], "{:?}", found);
+    assert!(found[0].1.contains("spanning all 10 stacks"));
+    assert!(found[1].1.contains("single-digit"));
+    assert!(found[2].1.contains("at most the 1 crates on stack 10"));
+    assert!(found[3].1.contains("1 to 10"));
+    assert!(found[4].1.contains("top of stack 10"));
+}
+
+#[test]
+fn day13_every_misplaced_line() {
+    let input = "[1]\n[10]\n\n\n[3]\n[4]\n[5]\n\n[9]\n\n[7]\n[7]\n\n[8]";
+    let found = problems(day13::Day13::lint(input));
+    assert_eq!(found.iter().map(|(line, _)| *line).collect::<Vec<usize>>(), vec![4, 7, 10, 12, 15], "{:?}", found);
+    assert!(found[0].1.contains("one blank line apart"));
+    assert!(found[1].1.contains("blank line between pairs"));
+    assert!(found[2].1.contains("right packet of the pair"));
+    assert!(found[3].1.contains("different from the left"));
+    assert!(found[4].1.contains("last pair"));
+}
+
+#[test]
+fn day14_every_diagonal_segment() {
+    let input = "498,4 -> 498,6 -> 496,8\n503,4 -> 502,4 -> 502,9 -> 494,10 -> 490,9\n500,x\n";
+    let found = problems(day14::Day14::lint(input));
+    assert_eq!(found.iter().map(|(line, _)| *line).collect::<Vec<usize>>(), vec![1, 2, 2, 3], "{:?}", found);
+    assert!(found[0].1.contains("in line with the previous one"));
+    assert!(found[3].1.contains("a point like"));
+}
+
+#[test]
+fn day16_start_and_reachability() {
+    let input = "\
+Valve BB has flow rate=13; tunnels lead to valves CC, ZZ
+Valve CC has flow rate=2; tunnel leads to valve BB
+Valve DD has flow rate=20; tunnels lead to valves DD
+";
+    let found = problems(day16::Day16::lint(input));
+    assert_eq!(found.iter().map(|(line, _)| *line).collect::<Vec<usize>>(), vec![1, 1], "{:?}", found);
+    assert!(found.iter().any(|(_, expected)| expected.contains("AA to start from")));
+
+    let with_start = format!("Valve AA has flow rate=0; tunnel leads to valve BB\n{}", input.replace(", ZZ", ""));
+    assert_eq!(lines(day16::Day16::lint(&with_start)), vec![4]);
+}
@@ -0,0 +1,98 @@
+// Parameters come from the config file, its tables for the input being run, then `--param` flags, and are
+// checked against what each day reads.
+use std::collections::HashMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use aoc22::days::{get_day, Day};
+use aoc22::input::read_to_string;
+use aoc22::params::{self, ParamConfig};
+use aoc22::solution::Solution;
+use aoc22::day15;
+
+
+// tests run at once, each config gets its own file
+static CONFIGS: AtomicUsize = AtomicUsize::new(0);
+
+fn config(file: &str, overrides: &[&str]) -> ParamConfig {
+    let n = CONFIGS.fetch_add(1, Ordering::Relaxed);
+    let path = env::temp_dir().join(format!("aoc22-params-{}-{}.toml", std::process::id(), n));
+    fs::write(&path, file).unwrap();
+    let mut args = vec!["--params".to_string(), path.to_str().unwrap().to_string()];
+    for spec in overrides {
+        args.extend(["--param".to_string(), spec.to_string()]);
+    }
+    return ParamConfig::from_args(&args).unwrap();
+}
+
+fn resolve(config: &ParamConfig, days: &[u32], input: &str) -> Result<HashMap<(u32, String), String>, String> {
+    let days = days.iter().map(|n| get_day(*n).unwrap()).collect::<Vec<&Day>>();
+    return config.resolve(&days, input);
+}
+
+fn value(values: &HashMap<(u32, String), String>, day: u32, name: &str) -> Option<String> {
+    return values.get(&(day, name.to_string())).cloned();
+}
+
+
+#[test]
+fn input_tables_and_flags_layer_over_the_day_table() {
+    let file = "[15]\nrow = 5\nsearch_limit = 30\n\n[15.ex]\nrow = 10\n";
+    let values = resolve(&config(file, &[]), &[15], "in").unwrap();
+    assert_eq!(value(&values, 15, "row").as_deref(), Some("5"));
+
+    let values = resolve(&config(file, &[]), &[15], "ex").unwrap();
+    assert_eq!(value(&values, 15, "row").as_deref(), Some("10"));
+    assert_eq!(value(&values, 15, "search_limit").as_deref(), Some("30"));
+
+    let values = resolve(&config(file, &["row=11", "17.width=9"]), &[15], "ex").unwrap();
+    assert_eq!(value(&values, 15, "row").as_deref(), Some("11"));
+    assert_eq!(value(&values, 17, "width").as_deref(), Some("9"));
+}
+
+#[test]
+fn flags_without_a_day_go_to_the_days_that_have_them() {
+    let values = resolve(&config("", &["part1_rounds=5"]), &[9, 11], "in").unwrap();
+    assert_eq!(value(&values, 11, "part1_rounds").as_deref(), Some("5"));
+    assert_eq!(values.len(), 1);
+
+    assert!(resolve(&config("", &["part1_rounds=5"]), &[9], "in").is_err());
+}
+
+#[test]
+fn unknown_names_and_bad_values_are_errors() {
+    let error = resolve(&config("", &["15.rwo=10"]), &[15], "in").unwrap_err();
+    assert!(error.contains("no parameter rwo") && error.contains("row"), "{}", error);
+
+    let error = resolve(&config("", &["15.row=ten"]), &[15], "in").unwrap_err();
+    assert!(error.contains("can't be \"ten\""), "{}", error);
+
+    // even in tables for other inputs
+    let error = resolve(&config("[15.ex]\nrow = 1.5\n", &[]), &[15], "in").unwrap_err();
+    assert!(error.contains("can't be \"1.5\""), "{}", error);
+
+    // numbers the day can't work with
+    let error = resolve(&config("", &["17.width=3"]), &[17], "in").unwrap_err();
+    assert!(error.contains("can't be \"3\", it's at least 6"), "{}", error);
+    assert!(resolve(&config("", &["17.width=6"]), &[17], "in").is_ok());
+    let error = resolve(&config("", &["part2_knots=0"]), &[9], "in").unwrap_err();
+    assert!(error.contains("part2_knots can't be \"0\", it's at least 1"), "{}", error);
+
+    assert!(resolve(&config("[03]\nrounds = 1\n", &[]), &[3], "in").unwrap_err().contains("no parameters"));
+    assert!(resolve(&config("[30]\nrounds = 1\n", &[]), &[3], "in").unwrap_err().contains("no such day"));
+}
+
+#[test]
+fn the_example_runs_with_its_own_row() {
+    // the repo's config has the example's values, and the parts read them
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(params::PARAMS_PATH);
+    let config = ParamConfig::from_args(&["--params".to_string(), path.to_str().unwrap().to_string()]).unwrap();
+    params::install(resolve(&config, &[15], "ex").unwrap());
+
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join("src/15rs/ex.in.txt");
+    let input = day15::Day15::parse(&read_to_string(path).unwrap()).unwrap();
+    assert_eq!(day15::Day15::part1(&input), 26);
+    assert_eq!(day15::Day15::part2(&input), day15::TuningFrequency(Some(56000011)));
+}
@@ -0,0 +1,52 @@
+// Malformed inputs are reported with the line and column they go wrong at, not a panic.
+use std::path::Path;
+
+use aoc22::error::ParseError;
+use aoc22::solution::Solution;
+use aoc22::{day01, day05, day10, day12, day13, day16};
+
+
+fn parse_error<S: Solution>(input: &str) -> ParseError {
+    return match S::parse(input) {
+        Ok(_) => panic!("parsed {:?}", input),
+        Err(e) => e,
+    };
+}
+
+
+#[test]
+fn reports_line_and_column() {
+    let error = parse_error::<day01::Day01>("100\n200\n\n3x0\n");
+    assert_eq!(error.to_string(), "<input>:4:1: expected a calorie count, found \"3x0\"");
+
+    let error = parse_error::<day12::Day12>("Sabc\nabEz\nab#c\n");
+    assert_eq!(error.to_string(), "<input>:3:3: expected a height from a to z, S or E, found \"#\"");
+}
+
+#[test]
+fn reports_missing_parts() {
+    let error = parse_error::<day05::Day05>("    [D]\n[N] [C]\n 1   2\n");
+    assert!(matches!(error, ParseError::Missing { .. }), "{}", error);
+
+    let error = parse_error::<day16::Day16>("Valve BB has flow rate=0; tunnels lead to valves BB\n");
+    assert!(error.to_string().contains("AA"), "{}", error);
+}
+
+#[test]
+fn reports_packets_that_compare_equal() {
+    // a list of one number compares as the number, so part 2 couldn't put these in order
+    let error = parse_error::<day13::Day13>("[1,[2]]\n[[1],2]\n");
+    assert_eq!(error.to_string(), "<input>:2:1: expected a packet different from the left one, found \"[[1],2]\"");
+
+    let error = parse_error::<day13::Day13>("[1]\n[3]\n\n[4]\n[[[2]]]\n");
+    assert!(error.to_string().starts_with("<input>:5:1: expected a packet different from the divider packets"), "{}", error);
+
+    let error = parse_error::<day13::Day13>("[1]\n[3]\n\n[4]\n[[1]]\n");
+    assert!(error.to_string().contains("different from the one on line 1"), "{}", error);
+}
+
+#[test]
+fn names_the_file() {
+    let error = parse_error::<day10::Day10>("noop\njump 3\n").in_file(Path::new("src/10rs/in.txt"));
+    assert_eq!(error.to_string(), "src/10rs/in.txt:2:1: expected \"noop\" or \"addx <number>\", found \"jump 3\"");
+}
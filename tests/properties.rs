@@ -0,0 +1,409 @@
+// Random structures printed the way the puzzle prints them parse back to the same structures, and parsing
+// mangled inputs gives an error, never a panic. The fuzz/ crate runs the same parsers under libFuzzer.
+use std::cmp::Ordering;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::panic;
+
+use num_bigint::BigUint;
+
+use aoc22::days::DAYS;
+use aoc22::generate::{Rng, LOWERCASE, UPPERCASE};
+use aoc22::solution::Solution;
+use aoc22::*;
+
+
+const CASES: u64 = 200;
+
+fn letters(rng: &mut Rng, alphabet: &str, length: usize) -> String {
+    let alphabet = alphabet.chars().collect::<Vec<char>>();
+    return (0..length).map(|_| *rng.pick(&alphabet)).collect();
+}
+
+fn parsed<S: Solution>(input: &str) -> S::Input {
+    return S::parse(input).unwrap_or_else(|e| panic!("{}\n{}", e, input));
+}
+
+
+#[test]
+fn calories_round_trip() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        // down to no elves at all, fewer than both parts ask about
+        let elves = (0..rng.range(0, 8))
+            .map(|_| (0..rng.range(1, 5)).map(|_| rng.range(0, 100000) as i32).collect::<Vec<i32>>())
+            .collect::<Vec<Vec<i32>>>();
+        let text = elves.iter().map(|elf| elf.iter().map(|c| format!("{}\n", c)).collect::<String>()).collect::<Vec<String>>();
+
+        let mut totals = elves.iter().map(|elf| elf.iter().sum::<i32>()).collect::<Vec<i32>>();
+        totals.sort();
+        let food_counts = parsed::<day01::Day01>(&text.join("\n"));
+        assert_eq!(food_counts, totals);
+        totals.reverse();
+        assert_eq!(day01::Day01::part1(&food_counts), totals.iter().take(1).sum::<i32>());
+        assert_eq!(day01::Day01::part2(&food_counts), totals.iter().take(3).sum::<i32>());
+    }
+}
+
+#[test]
+fn assignments_round_trip() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let assignments = (0..rng.range(1, 10))
+            .map(|_| {
+                (0..2).map(|_| {
+                    let start = rng.range(0, 100) as i32;
+                    (start, start + rng.range(0, 100) as i32)
+                }).collect::<Vec<(i32, i32)>>()
+            })
+            .collect::<Vec<Vec<(i32, i32)>>>();
+        let text = assignments
+            .iter()
+            .map(|pair| format!("{}-{},{}-{}\n", pair[0].0, pair[0].1, pair[1].0, pair[1].1))
+            .collect::<String>();
+        assert_eq!(parsed::<day04::Day04>(&text), assignments);
+    }
+}
+
+#[test]
+fn crate_stacks_round_trip() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let stack_count = rng.range(1, 10) as usize;
+        let mut stacks = (0..stack_count)
+            .map(|_| {
+                let height = rng.range(0, 6) as usize;
+                letters(&mut rng, UPPERCASE, height).chars().collect::<Vec<char>>()
+            })
+            .collect::<Vec<Vec<char>>>();
+        stacks[rng.below(stack_count)].push('Z');
+        let instructions = (0..rng.range(0, 6))
+            .map(|_| (rng.range(1, 4) as i32, rng.below(stack_count) as i32, rng.below(stack_count) as i32))
+            .collect::<Vec<day05::Instruction>>();
+
+        // rows are padded to the full width, from the tallest stack down
+        let height = stacks.iter().map(Vec::len).max().unwrap();
+        let mut text = String::new();
+        for row in (0..height).rev() {
+            let cells = stacks
+                .iter()
+                .map(|stack| stack.get(row).map_or("   ".to_string(), |c| format!("[{}]", c)))
+                .collect::<Vec<String>>();
+            text.push_str(&format!("{}\n", cells.join(" ")));
+        }
+        let numbers = (1..=stack_count).map(|n| format!(" {} ", n)).collect::<Vec<String>>();
+        text.push_str(&format!("{}\n\n", numbers.join(" ")));
+        for (quantity, source, target) in &instructions {
+            text.push_str(&format!("move {} from {} to {}\n", quantity, source + 1, target + 1));
+        }
+
+        assert_eq!(parsed::<day05::Day05>(&text), (stacks, instructions), "\n{}", text);
+    }
+}
+
+// a directory's own files, and its subdirectories by name
+struct Dir {
+    files: Vec<u64>,
+    dirs: BTreeMap<String, Dir>,
+}
+
+fn random_dir(rng: &mut Rng, depth: usize) -> Dir {
+    let mut dir = Dir {
+        files: (0..rng.range(0, 4)).map(|_| rng.range(1, 300000) as u64).collect(),
+        dirs: BTreeMap::new(),
+    };
+    if depth < 4 {
+        for _ in 0..rng.range(0, 4) {
+            let length = rng.range(1, 4) as usize;
+            let name = letters(rng, LOWERCASE, length);
+            dir.dirs.insert(name, random_dir(rng, depth + 1));
+        }
+    }
+    return dir;
+}
+
+// prints the session that lists `dir`, returns its total size and records every directory's under its path
+fn print_session(rng: &mut Rng, dir: &Dir, path: &mut Vec<String>, text: &mut String, sizes: &mut HashMap<String, BigUint>) -> u64 {
+    text.push_str("$ ls\n");
+    let mut listing = dir.dirs.keys().map(|name| format!("dir {}", name)).collect::<Vec<String>>();
+    listing.extend(dir.files.iter().enumerate().map(|(i, size)| format!("{} f{}.txt", size, i)));
+    rng.shuffle(&mut listing);
+    for line in listing {
+        text.push_str(&format!("{}\n", line));
+    }
+
+    let mut total = dir.files.iter().sum::<u64>();
+    for (name, subdir) in &dir.dirs {
+        text.push_str(&format!("$ cd {}\n", name));
+        path.push(name.clone());
+        total += print_session(rng, subdir, path, text, sizes);
+        path.pop();
+        text.push_str("$ cd ..\n");
+    }
+    sizes.insert(path.join("/"), BigUint::from(total));
+    return total;
+}
+
+#[test]
+fn shell_sessions_round_trip() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let root = random_dir(&mut rng, 0);
+        let mut text = "$ cd /\n".to_string();
+        let mut sizes = HashMap::new();
+        print_session(&mut rng, &root, &mut vec!["/".to_string()], &mut text, &mut sizes);
+        assert_eq!(parsed::<day07::Day07>(&text), sizes, "\n{}", text);
+    }
+}
+
+#[test]
+fn tree_grids_round_trip() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let (width, height) = (rng.range(1, 12), rng.range(1, 12));
+        let grid = (0..height).map(|_| (0..width).map(|_| rng.range(0, 10) as i32).collect()).collect::<Vec<Vec<i32>>>();
+        let text = grid
+            .iter()
+            .map(|row| row.iter().map(|h| format!("{}", h)).collect::<String>() + "\n")
+            .collect::<String>();
+        assert_eq!(parsed::<day08::Day08>(&text), grid);
+    }
+}
+
+#[test]
+fn rope_moves_round_trip() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let moves = (0..rng.range(1, 20))
+            .map(|_| (rng.pick(&["L", "R", "U", "D"]).to_string(), rng.range(1, 100) as i32))
+            .collect::<Vec<day09::Move>>();
+        let text = moves.iter().map(|(dir, n)| format!("{} {}\n", dir, n)).collect::<String>();
+        assert_eq!(parsed::<day09::Day09>(&text), moves);
+    }
+}
+
+// a day 13 packet, ordered the way the puzzle orders them
+#[derive(Clone, PartialEq, Eq)]
+enum Packet {
+    Number(u32),
+    List(Vec<Packet>),
+}
+
+impl Packet {
+    fn random(rng: &mut Rng, depth: usize) -> Packet {
+        if depth > 0 && (depth > 3 || rng.chance(0.5)) {
+            return Packet::Number(rng.range(0, 12) as u32);
+        }
+        return Packet::List((0..rng.range(0, 4)).map(|_| Packet::random(rng, depth + 1)).collect());
+    }
+
+    fn print(&self) -> String {
+        return match self {
+            Packet::Number(n) => n.to_string(),
+            Packet::List(items) => format!("[{}]", items.iter().map(Packet::print).collect::<Vec<String>>().join(",")),
+        };
+    }
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        return match (self, other) {
+            (Packet::Number(a), Packet::Number(b)) => a.cmp(b),
+            (Packet::List(a), Packet::List(b)) => a.cmp(b),
+            (Packet::Number(a), list) => Packet::List(vec![Packet::Number(*a)]).cmp(list),
+            (list, Packet::Number(b)) => list.cmp(&Packet::List(vec![Packet::Number(*b)])),
+        };
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        return Some(self.cmp(other));
+    }
+}
+
+#[test]
+fn packets_round_trip_and_compare() {
+    let mut distinct_inputs = 0;
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let pair_count = rng.range(1, 8) as usize;
+        let mut pairs = Vec::new();
+        while pairs.len() < pair_count {
+            let (left, right) = (Packet::random(&mut rng, 0), Packet::random(&mut rng, 0));
+            if left.cmp(&right) != Ordering::Equal {
+                pairs.push((left, right));
+            }
+        }
+        let text = pairs.iter().map(|(left, right)| format!("{}\n{}\n", left.print(), right.print())).collect::<Vec<String>>();
+        let text = text.join("\n");
+
+        // part 2 sorts them all with the dividers, so parse takes them only when no two compare equal
+        let dividers = [2, 6].map(|n| Packet::List(vec![Packet::List(vec![Packet::Number(n)])]));
+        let packets = pairs.iter().flat_map(|(left, right)| [left, right]).chain(&dividers).collect::<Vec<&Packet>>();
+        let distinct = packets.iter().enumerate().all(|(i, a)| packets[i + 1..].iter().all(|b| a.cmp(b) != Ordering::Equal));
+        if !distinct {
+            assert!(day13::Day13::parse(&text).is_err(), "\n{}", text);
+            continue;
+        }
+        distinct_inputs += 1;
+
+        let printed = pairs.iter().map(|(left, right)| (left.print(), right.print())).collect::<Vec<(String, String)>>();
+        let parsed_pairs = parsed::<day13::Day13>(&text);
+        assert_eq!(parsed_pairs, printed);
+        let in_order = pairs.iter().enumerate().filter(|(_, (left, right))| left < right).map(|(i, _)| i + 1).sum::<usize>();
+        assert_eq!(day13::Day13::part1(&parsed_pairs), in_order, "\n{}", text);
+        let mut sorted = packets.clone();
+        sorted.sort();
+        let position = |divider: &Packet| sorted.iter().position(|packet| *packet == divider).unwrap() + 1;
+        assert_eq!(day13::Day13::part2(&parsed_pairs), (position(&dividers[0]) * position(&dividers[1])) as i32, "\n{}", text);
+    }
+    assert!(distinct_inputs > CASES / 4, "only {} inputs had no packets comparing equal", distinct_inputs);
+}
+
+#[test]
+fn rock_paths_round_trip() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let mut rocks = HashSet::new();
+        let mut text = String::new();
+        for _ in 0..rng.range(1, 4) {
+            let mut point = (rng.range(480, 520), rng.range(1, 20));
+            let mut path = vec![format!("{},{}", point.0, point.1)];
+            rocks.insert(point);
+            for segment in 0..rng.range(1, 4) {
+                let next = if segment % 2 == 0 { (rng.range(480, 520), point.1) } else { (point.0, rng.range(1, 20)) };
+                for x in point.0.min(next.0)..=point.0.max(next.0) {
+                    for y in point.1.min(next.1)..=point.1.max(next.1) {
+                        rocks.insert((x, y));
+                    }
+                }
+                point = next;
+                path.push(format!("{},{}", point.0, point.1));
+            }
+            text.push_str(&format!("{}\n", path.join(" -> ")));
+        }
+
+        let (grid, (source_column, _)) = parsed::<day14::Day14>(&text);
+        assert_eq!(grid[0][source_column as usize], '+');
+        // everything above the floor
+        let drawn = grid[..grid.len() - 1]
+            .iter()
+            .enumerate()
+            .flat_map(|(y, row)| row.iter().enumerate().filter(|(_, c)| **c == '#').map(move |(x, _)| (x as i64, y as i64)))
+            .map(|(x, y)| (x - source_column as i64 + 500, y))
+            .collect::<HashSet<(i64, i64)>>();
+        // the grid is only as wide as the sand can spread, out to the floor two below the lowest rock
+        let y_max = rocks.iter().map(|(_, y)| *y).max().unwrap();
+        let reachable = rocks.iter().filter(|(x, _)| (x - 500).abs() <= y_max + 1).cloned().collect::<HashSet<(i64, i64)>>();
+        assert!(drawn.is_subset(&rocks) && reachable.is_subset(&drawn), "\n{}", text);
+    }
+}
+
+#[test]
+fn sensor_reports_round_trip() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let pairs = (0..rng.range(1, 10))
+            .map(|_| {
+                let mut point = || (rng.range(-5000000, 5000000) as i32, rng.range(-5000000, 5000000) as i32);
+                (point(), point())
+            })
+            .collect::<Vec<(day15::Point, day15::Point)>>();
+        let text = pairs
+            .iter()
+            .map(|(s, b)| format!("Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", s.0, s.1, b.0, b.1))
+            .collect::<String>();
+        assert_eq!(parsed::<day15::Day15>(&text), pairs);
+    }
+}
+
+#[test]
+fn valve_scans_round_trip() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let valve_count = rng.range(2, 10) as usize;
+        let mut names = vec!["AA".to_string()];
+        while names.len() < valve_count {
+            let name = letters(&mut rng, UPPERCASE, 2);
+            if !names.contains(&name) {
+                names.push(name);
+            }
+        }
+        rng.shuffle(&mut names);
+
+        let mut text = String::new();
+        for name in &names {
+            let mut tunnels = names.iter().filter(|other| *other != name).cloned().collect::<Vec<String>>();
+            rng.shuffle(&mut tunnels);
+            tunnels.truncate(rng.range(1, tunnels.len() as i64 + 1) as usize);
+            let (tunnel, lead, valve) = if tunnels.len() == 1 { ("tunnel", "leads", "valve") } else { ("tunnels", "lead", "valves") };
+            text.push_str(&format!(
+                "Valve {} has flow rate={}; {} {} to {} {}\n", name, rng.range(0, 30), tunnel, lead, valve, tunnels.join(", ")
+            ));
+        }
+        // valves only show their names
+        assert_eq!(format!("{:?}", parsed::<day16::Day16>(&text)), format!("[{}]", names.join(", ")));
+    }
+}
+
+#[test]
+fn jet_patterns_round_trip() {
+    for seed in 0..CASES {
+        let mut rng = Rng::new(seed);
+        let jets = (0..rng.range(1, 50)).map(|_| *rng.pick(&['<', '>'])).collect::<Vec<char>>();
+        assert_eq!(parsed::<day17::Day17>(&format!("{}\n", jets.iter().collect::<String>())), jets);
+    }
+}
+
+
+const NOISE: &[char] = &['0', '1', '9', '-', ',', '[', ']', ' ', '\n', ':', '=', '$', 'a', 'x', 'A', '<', '>', 'é'];
+
+// a few random edits: inserting, deleting or replacing a character, cutting the rest off, or adding a long number
+fn mangle(rng: &mut Rng, text: &str) -> String {
+    let mut chars = text.chars().collect::<Vec<char>>();
+    for _ in 0..rng.range(1, 6) {
+        let at = rng.below(chars.len() + 1);
+        match rng.below(5) {
+            0 => chars.insert(at, *rng.pick(NOISE)),
+            1 if at < chars.len() => {
+                chars.remove(at);
+            }
+            2 if at < chars.len() => chars[at] = *rng.pick(NOISE),
+            3 => chars.truncate(at),
+            _ => {
+                for i in 0..rng.range(1, 12) as usize {
+                    chars.insert(at + i, *rng.pick(&['0', '5', '9']));
+                }
+            }
+        }
+    }
+    return chars.into_iter().collect();
+}
+
+fn parse_and_lint<S: Solution>(input: &str) {
+    let _ = S::parse(input);
+    let _ = S::lint(input);
+}
+
+#[test]
+fn mangled_inputs_never_panic() {
+    let parsers: [fn(&str); 17] = [
+        parse_and_lint::<day01::Day01>, parse_and_lint::<day02::Day02>, parse_and_lint::<day03::Day03>,
+        parse_and_lint::<day04::Day04>, parse_and_lint::<day05::Day05>, parse_and_lint::<day06::Day06>,
+        parse_and_lint::<day07::Day07>, parse_and_lint::<day08::Day08>, parse_and_lint::<day09::Day09>,
+        parse_and_lint::<day10::Day10>, parse_and_lint::<day11::Day11>, parse_and_lint::<day12::Day12>,
+        parse_and_lint::<day13::Day13>, parse_and_lint::<day14::Day14>, parse_and_lint::<day15::Day15>,
+        parse_and_lint::<day16::Day16>, parse_and_lint::<day17::Day17>,
+    ];
+    for (day, parse) in DAYS.iter().zip(parsers) {
+        for seed in 0..CASES * 5 {
+            let mut rng = Rng::new(seed);
+            let generated = (day.generate)(&mut rng, 3);
+            let input = mangle(&mut rng, &generated);
+            if let Err(e) = panic::catch_unwind(|| parse(&input)) {
+                let message = e.downcast_ref::<String>().cloned().or_else(|| e.downcast_ref::<&str>().map(|s| s.to_string()));
+                panic!("day {:02} panicked on {:?}: {}", day.number, input, message.unwrap_or_default());
+            }
+        }
+    }
+}
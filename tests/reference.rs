@@ -0,0 +1,130 @@
+// Differential tests of the days' shortcuts against their slow reference solvers, on small generated inputs.
+// Each reports the first input the two disagree on.
+use aoc22::generate::Rng;
+use aoc22::render::Renderer;
+use aoc22::solution::Solution;
+use aoc22::{day11, day15, day16, day17};
+
+
+const SEEDS: u64 = 50;
+
+
+// counts the frames of an animation without keeping them
+#[derive(Default)]
+struct FrameCount(i64);
+
+impl Renderer for FrameCount {
+    fn frame(&mut self, _frame: &str) {
+        self.0 += 1;
+    }
+}
+
+
+#[test]
+fn day11_worry_reduction_matches_whole_numbers() {
+    for seed in 0..SEEDS {
+        let mut rng = Rng::new(seed);
+        let size = rng.range(2, 6) as usize;
+        let rounds = rng.range(1, 12) as i32;
+        let input = day11::generate(&mut rng, size);
+        let monkeys = day11::Day11::parse(&input).unwrap();
+        for relief in [true, false] {
+            let fast = day11::run_rounds(monkeys.clone(), rounds, relief);
+            let slow = day11::reference::run_rounds(&monkeys, rounds, relief);
+            assert_eq!(fast, slow, "seed {}, {} rounds, relief {}:\n{}", seed, rounds, relief, input);
+        }
+    }
+}
+
+#[test]
+fn day15_shortcuts_match_checking_every_point() {
+    let limit = 20;
+    let mut checked = 0;
+    let mut no_gap_checked = 0;
+    for seed in 0..SEEDS {
+        let mut rng = Rng::new(seed);
+        let input = match day15::reference::generate_small(&mut rng, 200, limit) {
+            Some(input) => input,
+            None => continue,
+        };
+        checked += 1;
+        let pairs = day15::Day15::parse(&input).unwrap();
+        let row = rng.range(0, limit as i64 + 1) as i32;
+        assert_eq!(
+            day15::get_no_beacon_count(&pairs, row),
+            day15::reference::get_no_beacon_count(&pairs, row),
+            "seed {}, row {}:\n{}", seed, row, input
+        );
+        let frequency = day15::reference::get_tuning_frequency(&pairs, limit);
+        assert_eq!(
+            day15::get_tuning_frequency(&pairs, limit, day15::TUNING_MULTIPLIER.default),
+            frequency,
+            "seed {}:\n{}", seed, input
+        );
+
+        // and with a sensor on the gap, covering it, there's no distress beacon left to find; that means trying
+        // every group of four sensors, so only on the smaller fields
+        if pairs.len() > 30 {
+            continue;
+        }
+        no_gap_checked += 1;
+        let multiplier = day15::TUNING_MULTIPLIER.default;
+        let gap = frequency.map(|frequency| ((frequency / multiplier) as i32, (frequency % multiplier) as i32)).unwrap();
+        let covered = format!("{}Sensor at x={}, y={}: closest beacon is at x={}, y={}\n", input, gap.0, gap.1, gap.0 + 1, gap.1);
+        let pairs = day15::Day15::parse(&covered).unwrap();
+        assert_eq!(day15::reference::get_tuning_frequency(&pairs, limit), None, "seed {}:\n{}", seed, covered);
+        assert_eq!(day15::get_tuning_frequency(&pairs, limit, multiplier), None, "seed {}:\n{}", seed, covered);
+    }
+    assert!(checked > SEEDS / 2, "only {} fields had a single gap", checked);
+    assert!(no_gap_checked > SEEDS / 2, "only {} fields were small enough to cover the gap of", no_gap_checked);
+}
+
+#[test]
+fn day16_search_matches_every_move() {
+    for seed in 0..SEEDS {
+        let mut rng = Rng::new(seed);
+        let size = rng.range(2, 16) as usize;
+        let input = day16::generate(&mut rng, size);
+        let valves = day16::Day16::parse(&input).unwrap();
+        assert_eq!(
+            day16::find_best_solo_pressure_sum(&valves, 30),
+            day16::reference::find_best_solo_pressure_sum(&valves, 30),
+            "seed {}:\n{}", seed, input
+        );
+    }
+}
+
+#[test]
+fn day16_pairs_match_every_move() {
+    for seed in 0..SEEDS {
+        let mut rng = Rng::new(seed);
+        let size = rng.range(2, 16) as usize;
+        let input = day16::generate(&mut rng, size);
+        let valves = day16::Day16::parse(&input).unwrap();
+        assert_eq!(
+            day16::find_best_path_pressure_sum(&valves, 26),
+            day16::reference::find_best_path_pressure_sum(&valves, 26),
+            "seed {}:\n{}", seed, input
+        );
+    }
+}
+
+#[test]
+fn day17_cycle_skip_matches_dropping_every_rock() {
+    for seed in 0..SEEDS {
+        let mut rng = Rng::new(seed);
+        let size = rng.range(1, 40) as usize;
+        let rock_count = rng.range(1000, 3000);
+        let input = day17::generate(&mut rng, size);
+        let jet_pattern = day17::Day17::parse(&input).unwrap();
+        let rocks = day17::get_rocks();
+        let mut frames = FrameCount::default();
+        assert_eq!(
+            day17::get_rock_tower_height(&rocks, &jet_pattern, rock_count, 7, &mut frames),
+            day17::reference::get_rock_tower_height(&rocks, &jet_pattern, rock_count, 7),
+            "seed {}, {} rocks:\n{}", seed, rock_count, input
+        );
+        // every rock dropped is drawn at least twice, as it appears and once it's come to rest
+        assert!(frames.0 < 2 * rock_count, "seed {}, {} rocks: no cycle was skipped", seed, rock_count);
+    }
+}
@@ -0,0 +1,114 @@
+// The animated days draw the same state through any renderer that they solve headless.
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use aoc22::day10::{run_program, sample_cycles, Day10};
+use aoc22::day14::{process_sand, Day14};
+use aoc22::day17::{get_rock_tower_height, get_rocks, Day17};
+use aoc22::input::read_to_string;
+use aoc22::render::{renderer, Cast, Headless, Renderer};
+use aoc22::solution::Solution;
+
+
+#[derive(Default)]
+struct Recorder {
+    frames: Vec<String>,
+}
+
+impl Renderer for Recorder {
+    fn frame(&mut self, frame: &str) {
+        self.frames.push(frame.to_string());
+    }
+}
+
+fn example<S: Solution>(day: &str) -> S::Input {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/{}rs/ex.in.txt", day));
+    return S::parse(&read_to_string(&path).unwrap()).unwrap();
+}
+
+
+#[test]
+fn day10_draws_a_frame_per_cycle() {
+    let program = example::<Day10>("10");
+    let mut recorder = Recorder::default();
+    let (total, screen) = run_program(&program, &sample_cycles(), &mut recorder);
+    assert_eq!(total, 13140);
+    assert_eq!(recorder.frames.len(), 240);
+    assert_eq!(recorder.frames.last().unwrap(), &screen);
+}
+
+#[test]
+fn day14_draws_each_grain() {
+    let (mut grid, sand_source) = example::<Day14>("14");
+    let mut recorder = Recorder::default();
+    let total = process_sand(&mut grid, sand_source, false, &mut recorder);
+    assert_eq!(total, 24);
+    // the empty cave, each grain coming to rest and the one that falls out
+    assert_eq!(recorder.frames.len(), 26);
+    assert_eq!(recorder.frames.last().unwrap().matches('o').count(), 24);
+}
+
+#[test]
+fn day17_draws_falling_rocks() {
+    let jet_pattern = example::<Day17>("17");
+    let mut recorder = Recorder::default();
+    assert_eq!(get_rock_tower_height(&get_rocks(), &jet_pattern, 20, 7, &mut recorder), 36);
+    assert!(recorder.frames.iter().all(|frame| frame.ends_with("¯¯¯¯¯¯¯\n")));
+
+    // the floor is as wide as the cavern, every row above it too
+    let mut recorder = Recorder::default();
+    get_rock_tower_height(&get_rocks(), &jet_pattern, 20, 9, &mut recorder);
+    for frame in &recorder.frames {
+        assert!(frame.ends_with("\n¯¯¯¯¯¯¯¯¯\n"), "{}", frame);
+        assert!(frame.lines().all(|row| row.chars().count() == 9), "{}", frame);
+    }
+}
+
+#[test]
+fn headless_without_animation_flags() {
+    let args = ["--input".to_string(), "ex".to_string()];
+    assert!(!renderer(&args).unwrap().is_active());
+    assert!(renderer(&["--step".to_string()]).unwrap().is_active());
+    assert!(renderer(&["--fps".to_string(), "0".to_string()]).is_err());
+}
+
+#[test]
+fn cast_records_each_frame() {
+    let path = env::temp_dir().join(format!("aoc22-day14-{}.cast", std::process::id()));
+    let (mut grid, sand_source) = example::<Day14>("14");
+    let mut cast = Cast::new(path.to_str().unwrap(), 20, Box::new(Headless)).unwrap();
+    process_sand(&mut grid, sand_source, false, &mut cast);
+    cast.finish().unwrap();
+
+    let recording = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    let lines = recording.lines().map(|line| serde_json::from_str(line).unwrap()).collect::<Vec<serde_json::Value>>();
+    assert_eq!(lines[0]["version"], 2);
+    // the header is written first, then given the size of the largest frame once they're all in
+    assert_eq!(lines[0]["width"], grid[0].len());
+    assert_eq!(lines[0]["height"], grid.len() + 1);
+    assert_eq!(lines.len(), 1 + 26);
+    // headless recordings are spaced out at the given frame rate
+    assert_eq!(lines[2][0], 0.05);
+    assert_eq!(lines[2][1], "o");
+}
+
+#[test]
+fn cast_keeps_the_timing_of_a_run_on_screen() {
+    let path = env::temp_dir().join(format!("aoc22-day14-shown-{}.cast", std::process::id()));
+    let (mut grid, sand_source) = example::<Day14>("14");
+    // shown somewhere, so the frames are timed by the clock and not a frame a second
+    let mut cast = Cast::new(path.to_str().unwrap(), 1, Box::new(Recorder::default())).unwrap();
+    process_sand(&mut grid, sand_source, false, &mut cast);
+    cast.finish().unwrap();
+
+    let recording = fs::read_to_string(&path).unwrap();
+    fs::remove_file(&path).unwrap();
+    let times = recording.lines().skip(1).map(|line| {
+        serde_json::from_str::<serde_json::Value>(line).unwrap()[0].as_f64().unwrap()
+    }).collect::<Vec<f64>>();
+    assert_eq!(times.len(), 26);
+    assert!(times.windows(2).all(|pair| pair[0] <= pair[1]), "{:?}", times);
+    assert!(*times.last().unwrap() < 25.0, "{:?}", times);
+}
@@ -0,0 +1,72 @@
+// `aoc22 report` writes one page with every answer, time and picture inline.
+use std::env;
+use std::path::Path;
+use std::time::Duration;
+
+use aoc22::day12::{get_distance_field, Day12};
+use aoc22::days::get_day;
+use aoc22::image::{Image, Palette};
+use aoc22::input::read_to_string;
+use aoc22::report::{html, DayResults};
+use aoc22::solution::Solution;
+
+
+fn example(day: &str) -> String {
+    return read_to_string(Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/{}rs/ex.in.txt", day))).unwrap();
+}
+
+fn draw(day: u32) -> Image {
+    let picture = get_day(day).unwrap().picture.as_ref().unwrap();
+    return (picture.draw)(&example(&format!("{:02}", day)), &Palette::default()).unwrap();
+}
+
+
+#[test]
+fn the_days_draw_their_examples() {
+    let crt = draw(10);
+    assert_eq!((crt.width, crt.height), (40, 6));
+
+    let field = get_distance_field(&Day12::parse(&example("12")).unwrap(), 'S');
+    assert_eq!((field[0][0], field[2][5]), (0, 31));
+    assert_eq!((draw(12).width, draw(12).height), (8, 5));
+
+    // the cave's rows and the floor under them, filled to the source
+    let cave = draw(14);
+    assert_eq!(cave.height, 12);
+    assert_eq!(cave.pixels.iter().filter(|&&pixel| pixel == Palette::default().char_colour('o')).count(), 93);
+
+    let tower = draw(17);
+    assert_eq!(tower.height, 40);
+    assert!(tower.pixels.contains(&Palette::default().char_colour('@')));
+}
+
+#[test]
+fn the_page_holds_everything_inline() {
+    let results = vec![
+        DayResults {
+            number: 5,
+            parts: vec![Ok(("<CMZ>".to_string(), Duration::from_micros(50))), Ok(("MCD".to_string(), Duration::from_millis(2)))],
+            picture: None,
+        },
+        DayResults {
+            number: 10,
+            parts: vec![Ok(("13140".to_string(), Duration::from_micros(30))), Err("panicked: no screen".to_string())],
+            picture: Some(("the CRT screen", Ok(draw(10)))),
+        },
+        DayResults { number: 14, parts: vec![], picture: Some(("the sand", Err("src/14rs/in.txt:3: expected a rock path".to_string()))) },
+    ];
+    assert!(!results[0].failed() && results[1].failed() && results[2].failed());
+
+    let page = html(&results, "ex", Duration::from_secs(1));
+    assert!(page.starts_with("<!DOCTYPE html>") && page.ends_with("</html>\n"));
+    assert!(!page.contains("src=\"http") && !page.contains("href="));
+    assert!(page.contains("<td>&lt;CMZ&gt;</td>"));
+    assert!(page.contains("<td class=\"error\" colspan=\"2\">panicked: no screen</td>"));
+    // 40 by 6 squares at 12 CSS pixels each
+    assert!(page.contains("<img src=\"data:image/png;base64,iVBORw0KGgo"));
+    assert!(page.contains("width=\"480\" height=\"72\" alt=\"Day 10: the CRT screen\""));
+    assert!(page.contains("<p class=\"error\">src/14rs/in.txt:3: expected a rock path</p><figcaption>Day 14: the sand</figcaption>"));
+    // the slowest part's bar is full, the rest are on a log scale under it
+    assert!(page.contains("style=\"width: 100.0%\"></div>2.00ms"));
+    assert!(page.contains("style=\"width: 51.5%\"></div>50.00µs"));
+}
@@ -0,0 +1,67 @@
+// A traced run writes one record per step, and `aoc22 trace` finds them again.
+use std::env;
+use std::path::Path;
+
+use aoc22::day09::{self, Day09};
+use aoc22::day11::{self, Day11};
+use aoc22::input::read_to_string;
+use aoc22::solution::Solution;
+use aoc22::trace::{self, Query};
+use serde_json::{json, Value};
+
+
+fn example<S: Solution>(day: &str) -> S::Input {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR")).join(format!("src/{}rs/ex.in.txt", day));
+    return S::parse(&read_to_string(&path).unwrap()).unwrap();
+}
+
+fn query(path: &Path, args: &[&str]) -> Vec<Value> {
+    let args = args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    let (query, rest) = Query::from_args(&args).unwrap();
+    assert_eq!(rest, Vec::<String>::new());
+    return query.run(trace::read(path.to_str().unwrap()).unwrap()).unwrap();
+}
+
+
+// both days in one test, as the trace file is global
+#[test]
+fn traced_steps_can_be_queried() {
+    let path = env::temp_dir().join(format!("aoc22-trace-{}.jsonl", std::process::id()));
+    trace::open(path.to_str().unwrap()).unwrap();
+    assert_eq!(day09::get_tail_visited_count(&example::<Day09>("09"), 2), 13);
+    day11::run_rounds(example::<Day11>("11"), 20, true);
+    trace::close();
+
+    // the setup and one step per move
+    let day09 = query(&path, &["--day", "9"]);
+    assert_eq!(day09.len(), 9);
+    assert_eq!(day09[0], json!({"day": 9, "run": 1, "step": 0, "knots": 2}));
+    assert_eq!(query(&path, &["--day", "9", "--steps", "8", "--fields", "move,knots.1"]), vec![
+        json!({"move": "R 2", "knots.1": [1, 2]})
+    ]);
+    assert_eq!(query(&path, &["--where", "move=U 4", "--fields", "step"]), vec![json!({"step": 2})]);
+
+    // monkey 0 throws its two items to monkey 3 first
+    let throws = query(&path, &["--run", "2", "--has", "to", "--steps", "..3", "--fields", "monkey,worry,to"]);
+    assert_eq!(throws, vec![
+        json!({"monkey": 0, "worry": "500", "to": 3}),
+        json!({"monkey": 0, "worry": "620", "to": 3})
+    ]);
+    assert_eq!(query(&path, &["--day", "11", "--where", "round=20", "--steps", "5..=6"]).len(), 0);
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn step_ranges_and_bad_queries() {
+    let args = |args: &[&str]| args.iter().map(|arg| arg.to_string()).collect::<Vec<String>>();
+    for (steps, kept) in [("3", vec![3]), ("2..4", vec![2, 3]), ("2..=4", vec![2, 3, 4]), ("4..", vec![4, 5]), ("..2", vec![0, 1])] {
+        let (query, _) = Query::from_args(&args(&["--steps", steps])).unwrap();
+        let records = (0..6).map(|step| json!({"step": step})).filter(|record| query.matches(record));
+        assert_eq!(records.map(|record| record["step"].as_u64().unwrap()).collect::<Vec<u64>>(), kept, "{}", steps);
+    }
+    for bad in [&["--steps", "a..b"][..], &["--steps", "..0"], &["--day"], &["--run", "x"], &["--where", "round"]] {
+        assert!(Query::from_args(&args(bad)).is_err(), "{:?}", bad);
+    }
+    let (_, rest) = Query::from_args(&args(&["trace.jsonl", "--has", "rest"])).unwrap();
+    assert_eq!(rest, args(&["trace.jsonl"]));
+}
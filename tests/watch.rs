@@ -0,0 +1,39 @@
+// Watch mode notices files being saved, added and removed, and diffs a day's answers line by line.
+use std::env;
+use std::fs;
+
+use aoc22::watch::{changed, diff_lines, snapshot};
+
+
+#[test]
+fn notices_saved_added_and_removed_files() {
+    let dir = env::temp_dir().join(format!("aoc22-watch-{}", std::process::id()));
+    fs::create_dir_all(dir.join("nested")).unwrap();
+    fs::write(dir.join("main.rs"), "fn main() {}\n").unwrap();
+    fs::write(dir.join("nested/in.txt"), "1\n").unwrap();
+    let watched = [dir.clone(), dir.join("missing.txt")];
+    let before = snapshot(&watched);
+    assert_eq!(before.len(), 2);
+    assert!(changed(&before, &snapshot(&watched)).is_empty());
+
+    // the length changes even when the clock is too coarse to
+    fs::write(dir.join("nested/in.txt"), "1\n2\n").unwrap();
+    fs::write(dir.join("ex.in.txt"), "").unwrap();
+    fs::remove_file(dir.join("main.rs")).unwrap();
+    let after = snapshot(&watched);
+    assert_eq!(changed(&before, &after), vec![dir.join("ex.in.txt"), dir.join("main.rs"), dir.join("nested/in.txt")]);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn diffs_answers_by_line() {
+    let old = "Part 1 is: 13\nTotal is: 1\n";
+    assert_eq!(diff_lines(old, old), vec!["  Part 1 is: 13", "  Total is: 1"]);
+    assert_eq!(
+        diff_lines(old, "Part 1 is: 88\nTotal is: 1\n"),
+        vec!["- Part 1 is: 13", "+ Part 1 is: 88", "  Total is: 1"]
+    );
+    assert_eq!(diff_lines("", "a\n"), vec!["+ a"]);
+    assert_eq!(diff_lines("a\nb\n", "b\n"), vec!["- a", "  b"]);
+}